@@ -20,7 +20,9 @@ impl Pool for poolio::ThreadPool {
         Self::execute(self, f);
     }
 
-    fn join(&self) {}
+    fn join(&self) {
+        Self::join(self);
+    }
 }
 
 impl Pool for threadpool::ThreadPool {
@@ -0,0 +1,1001 @@
+//! This module provides cron-expression scheduling of jobs on top of a [`ThreadPool`].
+//!
+//! A [`Scheduler`] keeps a record of jobs together with [`Schedule`]s parsed from cron expressions and submits each job to a pool at its fire times.
+//! The fire times are computed in UTC or a [`FixedOffset`] from it according to a [`Clock`] which can be injected for testing.
+//!
+//! Cron expressions consist of five fields (minute, hour, day of month, month, day of week) or six fields (second in front).
+//! Each field is a comma-separated list of `*`, values `a`, ranges `a-b` and steps `*/n`, `a/n` or `a-b/n`.
+//! Months and days of week may also be given by their (case-insensitive) three-letter English names, where both 0 and 7 stand for Sunday.
+//! If both the day of month and the day of week are restricted (i.e., do not start with `*`), a day matches if either of them does (as in Vixie cron).
+//! Moreover, the macros `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly` are supported.
+//!
+//! # Examples
+//!
+//! Setting up a pool and a scheduler which runs a job every 15 minutes:
+//!
+//! ```
+//! use poolio::cron::{FixedOffset, Scheduler};
+//!
+//! let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+//! let scheduler = Scheduler::new();
+//!
+//! let id = scheduler
+//!     .schedule("0 */15 * * * *", FixedOffset::UTC, || println!("quarter"))
+//!     .unwrap();
+//! assert_eq!(1, scheduler.list().len());
+//!
+//! // nothing is due right now (most likely)
+//! scheduler.run_pending(&pool);
+//!
+//! assert!(scheduler.cancel(id));
+//! ```
+
+use crate::ThreadPool;
+
+use std::fmt;
+use std::panic::RefUnwindSafe;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crossbeam::channel::{bounded, Receiver, Sender, TrySendError};
+
+/// seconds per day
+const DAY: i64 = 24 * 60 * 60;
+
+/// number of days searched for the next fire time (enough for the 29th of February on a certain day of week)
+const HORIZON: i64 = 28 * 366;
+
+/// names of the months (starting with January)
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// names of the days of week (starting with Sunday)
+const DAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// [`ParseError`]s are returned when a cron expression cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// what is wrong with the expression
+    reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid cron expression: {}", self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Abbreviates returning a [`ParseError`].
+fn invalid<T>(reason: String) -> Result<T, ParseError> {
+    Err(ParseError { reason })
+}
+
+/// [`FixedOffset`]s are the time zones fire times are computed in, that is, UTC or a fixed offset from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedOffset {
+    /// seconds east of UTC
+    seconds: i32,
+}
+
+impl FixedOffset {
+    /// Coordinated Universal Time.
+    pub const UTC: Self = Self { seconds: 0 };
+
+    /// Sets up an offset east of UTC.
+    /// - `seconds` is the offset in seconds.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the offset is not less than a day.
+    ///
+    /// # Examples
+    ///
+    /// Setting up Central European Time:
+    ///
+    /// ```
+    /// let cet = poolio::cron::FixedOffset::east(60 * 60);
+    /// ```
+    pub fn east(seconds: i32) -> Self {
+        assert!(
+            i64::from(seconds).abs() < DAY,
+            "Offsets of a day or more are not allowed."
+        );
+        Self { seconds }
+    }
+
+    /// Sets up an offset west of UTC.
+    /// - `seconds` is the offset in seconds.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the offset is not less than a day.
+    pub fn west(seconds: i32) -> Self {
+        // the offset cannot be negated only if it is far beyond a day
+        Self::east(
+            seconds
+                .checked_neg()
+                .expect("Offsets of a day or more are not allowed."),
+        )
+    }
+
+    /// Tells the offset east of UTC in seconds.
+    pub fn seconds(&self) -> i32 {
+        self.seconds
+    }
+}
+
+/// [`Schedule`]s are parsed cron expressions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    /// the expression as it has been given
+    expression: String,
+    /// matching seconds (bit `n` for second `n`)
+    seconds: u64,
+    /// matching minutes (bit `n` for minute `n`)
+    minutes: u64,
+    /// matching hours (bit `n` for hour `n`)
+    hours: u64,
+    /// matching days of month (bit `n` for day `n`)
+    days_of_month: u64,
+    /// matching months (bit `n` for month `n`)
+    months: u64,
+    /// matching days of week (bit `n` for day `n` with Sunday as 0)
+    days_of_week: u64,
+    /// whether the day of month is restricted
+    dom_restricted: bool,
+    /// whether the day of week is restricted
+    dow_restricted: bool,
+}
+
+impl Schedule {
+    /// Parses a cron expression.
+    /// - `expression` is the expression with five or six fields (see the [module documentation](self)).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the expression is malformed.
+    ///
+    /// # Examples
+    ///
+    /// Parsing an expression for every weekday at noon:
+    ///
+    /// ```
+    /// let schedule = poolio::cron::Schedule::parse("0 12 * * MON-FRI").unwrap();
+    /// assert_eq!("0 12 * * MON-FRI", schedule.to_string());
+    /// assert!(poolio::cron::Schedule::parse("0 12 * *").is_err());
+    /// ```
+    pub fn parse(expression: &str) -> Result<Self, ParseError> {
+        let expanded = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 0 1 1 *",
+            "@monthly" => "0 0 0 1 * *",
+            "@weekly" => "0 0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 0 * * *",
+            "@hourly" => "0 0 * * * *",
+            trimmed => trimmed,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let fields = match fields.len() {
+            5 => {
+                let mut with_seconds = vec!["0"];
+                with_seconds.extend(fields);
+                with_seconds
+            }
+            6 => fields,
+            n => return invalid(format!("expected 5 or 6 fields but found {}", n)),
+        };
+
+        let days_of_week = parse_field(fields[5], 0, 7, &DAYS, 0)?;
+        // Sunday is both 0 and 7
+        let days_of_week = (days_of_week | (days_of_week >> 7)) & 0x7f;
+
+        Ok(Self {
+            expression: String::from(expression.trim()),
+            seconds: parse_field(fields[0], 0, 59, &[], 0)?,
+            minutes: parse_field(fields[1], 0, 59, &[], 0)?,
+            hours: parse_field(fields[2], 0, 23, &[], 0)?,
+            days_of_month: parse_field(fields[3], 1, 31, &[], 0)?,
+            months: parse_field(fields[4], 1, 12, &MONTHS, 1)?,
+            days_of_week,
+            dom_restricted: !fields[3].starts_with(['*', '?']),
+            dow_restricted: !fields[5].starts_with(['*', '?']),
+        })
+    }
+
+    /// Computes the first fire time of `self` strictly after a point in time.
+    /// - `after` is the point in time.
+    /// - `offset` is the time zone `self` is interpreted in.
+    ///
+    /// It returns [`None`] if `self` does not fire within the next 28 years (e.g. on the 30th of February).
+    ///
+    /// # Examples
+    ///
+    /// Computing when a job running every 15 minutes is due next:
+    ///
+    /// ```
+    /// use poolio::cron::{FixedOffset, Schedule};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let schedule = Schedule::parse("*/15 * * * *").unwrap();
+    /// let next = schedule.next_after(UNIX_EPOCH, FixedOffset::UTC);
+    /// assert_eq!(Some(UNIX_EPOCH + Duration::from_secs(15 * 60)), next);
+    /// ```
+    pub fn next_after(&self, after: SystemTime, offset: FixedOffset) -> Option<SystemTime> {
+        let offset = i64::from(offset.seconds);
+        // local time in seconds of the first candidate
+        let start = to_unix(after) + 1 + offset;
+
+        let first_day = start.div_euclid(DAY);
+        for day in first_day..first_day + HORIZON {
+            if !self.fires_on(day) {
+                continue;
+            }
+
+            let from = if day == first_day {
+                start.rem_euclid(DAY)
+            } else {
+                0
+            };
+            if let Some(time_of_day) = self.first_time_of_day(from) {
+                return Some(from_unix(day * DAY + time_of_day - offset));
+            }
+        }
+
+        None
+    }
+
+    /// Tells whether `self` fires on the day which is `day` days after 1970-01-01.
+    fn fires_on(&self, day: i64) -> bool {
+        let (_, month, day_of_month) = civil_from_days(day);
+        // 1970-01-01 was a Thursday
+        let day_of_week = (day + 4).rem_euclid(7);
+
+        let dom = is_set(self.days_of_month, i64::from(day_of_month));
+        let dow = is_set(self.days_of_week, day_of_week);
+        let day_matches = match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            _ => dom && dow,
+        };
+
+        is_set(self.months, i64::from(month)) && day_matches
+    }
+
+    /// Computes the first time of day (in seconds) not before `from` at which `self` fires.
+    fn first_time_of_day(&self, from: i64) -> Option<i64> {
+        // the fields are stepped through one after the other, starting from `from` only as long as the ones before match it
+        let hour = from / 3600;
+        values(self.hours, hour).find_map(|h| {
+            let from = if h == hour { from % 3600 } else { 0 };
+            let minute = from / 60;
+            values(self.minutes, minute).find_map(|m| {
+                let second = if m == minute { from % 60 } else { 0 };
+                values(self.seconds, second)
+                    .next()
+                    .map(|s| h * 3600 + m * 60 + s)
+            })
+        })
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = ParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        Self::parse(expression)
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// Parses a field of a cron expression into a bit set.
+/// - `field` is the field.
+/// - `min` and `max` are the bounds of the values.
+/// - `names` are the names of the values starting from `first`.
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    first: u32,
+) -> Result<u64, ParseError> {
+    let value = |v: &str| -> Result<u32, ParseError> {
+        let lowercase = v.to_ascii_lowercase();
+        let parsed = match names.iter().position(|name| *name == lowercase) {
+            Some(index) => Some(index as u32 + first),
+            None => v.parse().ok(),
+        };
+        match parsed {
+            Some(n) if (min..=max).contains(&n) => Ok(n),
+            _ => invalid(format!("'{}' is not a value in {}-{}", v, min, max)),
+        }
+    };
+
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => match step.parse::<u32>() {
+                Ok(step) if step > 0 => (range, Some(step)),
+                _ => return invalid(format!("'{}' is not a valid step", step)),
+            },
+            None => (part, None),
+        };
+
+        let (low, high) = match range {
+            "*" | "?" => (min, max),
+            _ => match range.split_once('-') {
+                Some((low, high)) => (value(low)?, value(high)?),
+                // a single value with a step ranges up to the maximum
+                None if step.is_some() => (value(range)?, max),
+                None => (value(range)?, value(range)?),
+            },
+        };
+        if low > high {
+            return invalid(format!("'{}' is not a valid range", range));
+        }
+
+        for n in (low..=high).step_by(step.unwrap_or(1) as usize) {
+            bits |= 1 << n;
+        }
+    }
+
+    Ok(bits)
+}
+
+/// Tells whether bit `n` is set in `bits`.
+fn is_set(bits: u64, n: i64) -> bool {
+    bits & (1 << n) != 0
+}
+
+/// Lists the values not less than `from` whose bits are set in `bits` (in ascending order).
+fn values(bits: u64, from: i64) -> impl Iterator<Item = i64> {
+    (from..64).filter(move |&n| is_set(bits, n))
+}
+
+/// Computes year, month and day of month of the day which is `days` days after 1970-01-01 (in the proleptic Gregorian calendar).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // shift the epoch to 0000-03-01 to have leap days at the end of the year
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month as u32, day as u32)
+}
+
+/// Converts `time` into seconds since the Unix epoch (rounding down).
+fn to_unix(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(e) => {
+            let before = e.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+        }
+    }
+}
+
+/// Converts seconds since the Unix epoch into a point in time.
+fn from_unix(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+/// Abstracts the clocks a [`Scheduler`] can be run by.
+pub trait Clock: Send + Sync + 'static {
+    /// Tells the current time.
+    fn now(&self) -> SystemTime;
+
+    /// Tells how long [`Scheduler::run`] has to wait for the clock to reach a certain time.
+    /// - `time` is the time to be reached.
+    ///
+    /// It returns [`None`] if the clock does not reach the time by itself, that is, if waiting only ends when the clock is moved (see [`Clock::watch`]).
+    /// By default, the clock moves in real time.
+    fn timeout(&self, time: SystemTime) -> Option<Duration> {
+        Some(time.duration_since(self.now()).unwrap_or(Duration::ZERO))
+    }
+
+    /// Hands over a way to wake up a [`Scheduler`] whenever the clock is moved other than by the passing of time.
+    /// - `wakeup` is what is to be woken up.
+    ///
+    /// By default, the clock is never moved that way and `wakeup` is dropped.
+    fn watch(&self, wakeup: Wakeup) {
+        drop(wakeup);
+    }
+}
+
+/// [`Wakeup`]s wake up [`Scheduler::run`] to take note of a moved [`Clock`].
+#[derive(Clone, Debug)]
+pub struct Wakeup(Sender<()>);
+
+impl Wakeup {
+    /// Wakes up the scheduler.
+    pub fn wake(&self) {
+        self.wake_if_alive();
+    }
+
+    /// Wakes up the scheduler and tells whether it still exists.
+    fn wake_if_alive(&self) -> bool {
+        // a pending wake-up is as good as a new one
+        !matches!(self.0.try_send(()), Err(TrySendError::Disconnected(())))
+    }
+}
+
+/// [`SystemClock`] is the clock of the system.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// [`ManualClock`]s are clocks which only move when told so, e.g. for testing.
+///
+/// Clones share the same time and wake up the schedulers run by any of them when moved.
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    /// the current time
+    now: Arc<Mutex<SystemTime>>,
+    /// the schedulers to be woken up when the clock is moved
+    watchers: Arc<Mutex<Vec<Wakeup>>>,
+}
+
+impl ManualClock {
+    /// Sets up a clock.
+    /// - `now` is the time the clock starts at.
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+            watchers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Sets the clock.
+    /// - `now` is the new time.
+    pub fn set(&self, now: SystemTime) {
+        *lock(&self.now) = now;
+        self.wake();
+    }
+
+    /// Moves the clock forward.
+    /// - `by` is how far the clock is moved.
+    pub fn advance(&self, by: Duration) {
+        *lock(&self.now) += by;
+        self.wake();
+    }
+
+    /// Wakes up the schedulers run by the clock and forgets the ones which have been dropped.
+    fn wake(&self) {
+        lock(&self.watchers).retain(Wakeup::wake_if_alive);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *lock(&self.now)
+    }
+
+    fn timeout(&self, _time: SystemTime) -> Option<Duration> {
+        None
+    }
+
+    fn watch(&self, wakeup: Wakeup) {
+        lock(&self.watchers).push(wakeup);
+    }
+}
+
+/// Locks `mutex` regardless of whether it has been poisoned (as no job ever runs under a lock).
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// [`ScheduleId`]s identify the jobs registered with a [`Scheduler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScheduleId(u64);
+
+/// [`ScheduleInfo`]s describe the jobs registered with a [`Scheduler`] (see [`Scheduler::list`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleInfo {
+    /// id of the job
+    pub id: ScheduleId,
+    /// when the job fires
+    pub schedule: Schedule,
+    /// time zone the schedule is interpreted in
+    pub offset: FixedOffset,
+    /// next fire time (if there is one)
+    pub next: Option<SystemTime>,
+}
+
+/// Types the jobs registered with a [`Scheduler`].
+type CronJob = Arc<dyn Fn() + RefUnwindSafe + Send + Sync + 'static>;
+
+/// [`Entry`] is a job registered with a [`Scheduler`].
+struct Entry {
+    /// description of the job
+    info: ScheduleInfo,
+    /// the job
+    job: CronJob,
+}
+
+/// [`Register`] is what a [`Scheduler`] keeps track of.
+struct Register {
+    /// the registered jobs
+    entries: Vec<Entry>,
+    /// id of the next registered job
+    next_id: u64,
+    /// whether [`Scheduler::run`] is supposed to return
+    stopped: bool,
+}
+
+/// [`Scheduler`]s submit jobs to a [`ThreadPool`] at the fire times of cron expressions.
+///
+/// The scheduler is driven either by calling [`Scheduler::run_pending`] regularly or by [`Scheduler::run`] which blocks until [`Scheduler::stop`] is called.
+/// Fire times which have been missed are not caught up on, that is, an overdue job is submitted once and then fires at its next fire time after now.
+pub struct Scheduler<C: Clock = SystemClock> {
+    /// what tells the time
+    clock: C,
+    /// the registered jobs
+    register: Mutex<Register>,
+    /// place to wake up [`Scheduler::run`] when the register changes
+    wake_s: Sender<()>,
+    /// place to wait for changes to the register
+    wake_r: Receiver<()>,
+}
+
+impl Scheduler {
+    /// Sets up a scheduler running by the system clock.
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> Scheduler<C> {
+    /// Sets up a scheduler running by a certain clock.
+    /// - `clock` is what tells the time.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a scheduler running by a manual clock and submitting a job when it is due:
+    ///
+    /// ```
+    /// use poolio::cron::{FixedOffset, ManualClock, Scheduler};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let pool = poolio::ThreadPool::new(1, poolio::PanicSwitch::Kill).unwrap();
+    /// let clock = ManualClock::new(UNIX_EPOCH);
+    /// let scheduler = Scheduler::with_clock(clock.clone());
+    ///
+    /// scheduler.schedule("@hourly", FixedOffset::UTC, || println!("hourly")).unwrap();
+    /// assert_eq!(0, scheduler.run_pending(&pool));
+    ///
+    /// clock.advance(Duration::from_secs(60 * 60));
+    /// assert_eq!(1, scheduler.run_pending(&pool));
+    /// ```
+    pub fn with_clock(clock: C) -> Self {
+        let (wake_s, wake_r) = bounded(1);
+        clock.watch(Wakeup(wake_s.clone()));
+        Self {
+            clock,
+            register: Mutex::new(Register {
+                entries: Vec::new(),
+                next_id: 0,
+                stopped: false,
+            }),
+            wake_s,
+            wake_r,
+        }
+    }
+
+    /// Registers a job.
+    /// - `expression` is the cron expression specifying the fire times of the job.
+    /// - `offset` is the time zone the expression is interpreted in.
+    /// - `f` is the job to be submitted at each fire time and has to be provided as a certain closure.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the expression is malformed.
+    pub fn schedule<F>(
+        &self,
+        expression: &str,
+        offset: FixedOffset,
+        f: F,
+    ) -> Result<ScheduleId, ParseError>
+    where
+        F: Fn() + RefUnwindSafe + Send + Sync + 'static,
+    {
+        let schedule = Schedule::parse(expression)?;
+        let next = schedule.next_after(self.clock.now(), offset);
+
+        let mut register = lock(&self.register);
+        let id = ScheduleId(register.next_id);
+        register.next_id += 1;
+        register.entries.push(Entry {
+            info: ScheduleInfo {
+                id,
+                schedule,
+                offset,
+                next,
+            },
+            job: Arc::new(f),
+        });
+        drop(register);
+
+        self.wake();
+        Ok(id)
+    }
+
+    /// Unregisters a job.
+    /// - `id` identifies the job.
+    ///
+    /// It returns whether there has been such a job.
+    pub fn cancel(&self, id: ScheduleId) -> bool {
+        let mut register = lock(&self.register);
+        let len = register.entries.len();
+        register.entries.retain(|entry| entry.info.id != id);
+        let cancelled = register.entries.len() < len;
+        drop(register);
+
+        self.wake();
+        cancelled
+    }
+
+    /// Lists the registered jobs (in the order they have been registered).
+    pub fn list(&self) -> Vec<ScheduleInfo> {
+        lock(&self.register)
+            .entries
+            .iter()
+            .map(|entry| entry.info.clone())
+            .collect()
+    }
+
+    /// Tells the earliest next fire time of the registered jobs.
+    pub fn next_due(&self) -> Option<SystemTime> {
+        lock(&self.register)
+            .entries
+            .iter()
+            .filter_map(|entry| entry.info.next)
+            .min()
+    }
+
+    /// Submits the jobs which are due to a pool.
+    /// - `pool` is where the jobs are run.
+    ///
+    /// It returns the number of submitted jobs.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if a job cannot be submitted (see [`ThreadPool::execute`]), that is, if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down.").
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    pub fn run_pending(&self, pool: &ThreadPool) -> usize {
+        let now = self.clock.now();
+
+        let mut due = Vec::new();
+        for entry in lock(&self.register).entries.iter_mut() {
+            if matches!(entry.info.next, Some(next) if next <= now) {
+                entry.info.next = entry.info.schedule.next_after(now, entry.info.offset);
+                due.push(Arc::clone(&entry.job));
+            }
+        }
+
+        // the jobs are submitted without holding the lock as submitting may block
+        let submitted = due.len();
+        for job in due {
+            pool.execute(move || job());
+        }
+        submitted
+    }
+
+    /// Submits the jobs to a pool at their fire times until [`Scheduler::stop`] is called.
+    /// - `pool` is where the jobs are run.
+    ///
+    /// In between, it waits for the clock to reach the next fire time (see [`Clock::timeout`]).
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if a job cannot be submitted (see [`Scheduler::run_pending`]).
+    ///
+    /// # Examples
+    ///
+    /// Setting up a scheduler running in the background for a moment:
+    ///
+    /// ```
+    /// use poolio::cron::{FixedOffset, Scheduler};
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let scheduler = Scheduler::new();
+    /// scheduler.schedule("* * * * * *", FixedOffset::UTC, || println!("tick")).unwrap();
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| scheduler.run(&pool));
+    ///     scheduler.stop();
+    /// });
+    /// ```
+    pub fn run(&self, pool: &ThreadPool) {
+        loop {
+            if lock(&self.register).stopped {
+                return;
+            }
+
+            self.run_pending(pool);
+
+            // sleep until the next job is due, the register changes or the clock is moved
+            match self.next_due().and_then(|next| self.clock.timeout(next)) {
+                Some(timeout) => {
+                    let _ = self.wake_r.recv_timeout(timeout);
+                }
+                None => {
+                    let _ = self.wake_r.recv();
+                }
+            }
+        }
+    }
+
+    /// Makes [`Scheduler::run`] return.
+    ///
+    /// Note that the scheduler cannot be run again afterwards.
+    pub fn stop(&self) {
+        lock(&self.register).stopped = true;
+        self.wake();
+    }
+
+    /// Wakes up [`Scheduler::run`] to take note of changes to the register.
+    fn wake(&self) {
+        // a pending wake-up is as good as a new one
+        let _ = self.wake_s.try_send(());
+    }
+}
+
+impl<C: Clock> fmt::Debug for Scheduler<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("schedules", &self.list())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::PanicSwitch;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Makes a point in time from seconds since the Unix epoch.
+    fn at(seconds: i64) -> SystemTime {
+        from_unix(seconds)
+    }
+
+    #[test]
+    fn test_schedule_parse() {
+        let schedule = Schedule::parse("0 */15 * * * *").unwrap();
+        assert_eq!(1, schedule.seconds);
+        assert_eq!(
+            (1 << 0) | (1 << 15) | (1 << 30) | (1 << 45),
+            schedule.minutes
+        );
+        assert!(!schedule.dom_restricted && !schedule.dow_restricted);
+
+        let schedule = Schedule::parse("5-10/2,59 0 1 jan,Dec sun,7").unwrap();
+        assert_eq!((1 << 5) | (1 << 7) | (1 << 9) | (1 << 59), schedule.minutes);
+        assert_eq!((1 << 1) | (1 << 12), schedule.months);
+        assert_eq!(1, schedule.days_of_week);
+        assert!(schedule.dom_restricted && schedule.dow_restricted);
+
+        let weekly = Schedule::parse("0 0 * * 0").unwrap();
+        assert_eq!(
+            Schedule {
+                expression: String::from("@weekly"),
+                ..weekly
+            },
+            Schedule::parse("@weekly").unwrap()
+        );
+
+        for malformed in [
+            "",
+            "* * * *",
+            "* * * * * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "* * * * 8",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+        ] {
+            assert!(Schedule::parse(malformed).is_err(), "{}", malformed);
+        }
+    }
+
+    #[test]
+    fn test_schedule_next_after() {
+        // 2024-02-28T23:59:30Z was a Wednesday
+        const START: i64 = 1_709_164_770;
+
+        let next = |expression: &str, offset: FixedOffset| {
+            Schedule::parse(expression)
+                .unwrap()
+                .next_after(at(START), offset)
+        };
+
+        assert_eq!(Some(at(START + 1)), next("* * * * * *", FixedOffset::UTC));
+        assert_eq!(Some(at(START + 30)), next("0 * * * * *", FixedOffset::UTC));
+        assert_eq!(
+            Some(at(START + 30 + 60 * 60)),
+            next("0 */15 1-23 * * *", FixedOffset::UTC)
+        );
+        // 2024 is a leap year
+        assert_eq!(Some(at(START + 30)), next("0 0 29 2 *", FixedOffset::UTC));
+        // the next Friday which is the 1st of March
+        assert_eq!(
+            Some(at(START + 30 + DAY)),
+            next("0 0 * * fri", FixedOffset::UTC)
+        );
+        // either the 15th or a Thursday which the 29th of February is
+        assert_eq!(Some(at(START + 30)), next("0 0 15 * thu", FixedOffset::UTC));
+        // midnight an hour east of UTC is an hour earlier
+        assert_eq!(
+            Some(at(START + 30 + DAY - 60 * 60)),
+            next("@daily", FixedOffset::east(60 * 60))
+        );
+        assert_eq!(
+            Some(at(START + 30 + 60 * 60)),
+            next("@daily", FixedOffset::west(60 * 60))
+        );
+        assert_eq!(None, next("0 0 30 2 *", FixedOffset::UTC));
+
+        // stepping field by field finds the same times of day as trying every second
+        for expression in ["* * * * * *", "30 5-10/5 */7 * * *", "0,59 59 23 * * *"] {
+            let schedule = Schedule::parse(expression).unwrap();
+            for from in (0..DAY).step_by(997).chain([DAY - 1]) {
+                let expected = (from..DAY).find(|&time| {
+                    is_set(schedule.hours, time / 3600)
+                        && is_set(schedule.minutes, time / 60 % 60)
+                        && is_set(schedule.seconds, time % 60)
+                });
+                assert_eq!(expected, schedule.first_time_of_day(from), "{}", expression);
+            }
+        }
+
+        assert_eq!(
+            Some(at(-DAY)),
+            Schedule::parse("@daily")
+                .unwrap()
+                .next_after(at(-DAY - 1), FixedOffset::UTC)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Offsets of a day or more are not allowed.")]
+    fn test_fixedoffset_west_min() {
+        FixedOffset::west(i32::MIN);
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!((1970, 1, 1), civil_from_days(0));
+        assert_eq!((1969, 12, 31), civil_from_days(-1));
+        assert_eq!((2000, 2, 29), civil_from_days(11_016));
+        assert_eq!((2024, 3, 1), civil_from_days(19_783));
+    }
+
+    #[test]
+    fn test_scheduler() {
+        let pool = ThreadPool::new(2, PanicSwitch::Respawn).unwrap();
+        let clock = ManualClock::new(at(0));
+        let scheduler = Scheduler::with_clock(clock.clone());
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let count = |n: usize| {
+            let counter = Arc::clone(&counter);
+            move || {
+                counter.fetch_add(n, Ordering::SeqCst);
+            }
+        };
+
+        let every_minute = scheduler
+            .schedule("* * * * *", FixedOffset::UTC, count(1))
+            .unwrap();
+        let hourly = scheduler
+            .schedule("@hourly", FixedOffset::UTC, count(100))
+            .unwrap();
+        assert!(scheduler
+            .schedule("@never", FixedOffset::UTC, count(0))
+            .is_err());
+
+        let listed = scheduler.list();
+        assert_eq!(
+            vec![every_minute, hourly],
+            listed.iter().map(|info| info.id).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(at(60)), listed[0].next);
+        assert_eq!(Some(at(60 * 60)), listed[1].next);
+        assert_eq!(Some(at(60)), scheduler.next_due());
+
+        assert_eq!(0, scheduler.run_pending(&pool));
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(1, scheduler.run_pending(&pool));
+        assert_eq!(0, scheduler.run_pending(&pool));
+
+        // missed fire times are not caught up on
+        clock.set(at(60 * 60));
+        assert_eq!(2, scheduler.run_pending(&pool));
+        assert_eq!(Some(at(60 * 60 + 60)), scheduler.list()[0].next);
+
+        assert!(scheduler.cancel(hourly));
+        assert!(!scheduler.cancel(hourly));
+        clock.set(at(2 * 60 * 60));
+        assert_eq!(1, scheduler.run_pending(&pool));
+
+        pool.join();
+        assert_eq!(103, counter.load(Ordering::SeqCst));
+
+        // the clock forgets the scheduler once it has been dropped
+        assert_eq!(1, lock(&clock.watchers).len());
+        drop(scheduler);
+        clock.advance(Duration::from_secs(60));
+        assert!(lock(&clock.watchers).is_empty());
+    }
+
+    #[test]
+    fn test_scheduler_run() {
+        const PATIENCE: Duration = Duration::from_secs(10);
+
+        let pool = ThreadPool::new(2, PanicSwitch::Respawn).unwrap();
+        let clock = ManualClock::new(at(0));
+        let scheduler = Scheduler::with_clock(clock.clone());
+
+        let (tick_s, tick_r) = crossbeam::channel::unbounded();
+        scheduler
+            .schedule("* * * * *", FixedOffset::UTC, move || {
+                tick_s.send(()).unwrap();
+            })
+            .unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| scheduler.run(&pool));
+
+            // the jobs are submitted by the running scheduler as the clock moves
+            for minute in 1..=3 {
+                clock.set(at(60 * minute));
+                tick_r.recv_timeout(PATIENCE).unwrap();
+            }
+
+            scheduler.stop();
+        });
+        pool.join();
+        assert!(tick_r.try_recv().is_err());
+    }
+}
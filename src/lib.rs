@@ -43,6 +43,15 @@
 //! ## Usage
 //!
 //! To use a poolio-[`ThreadPool`] you simply have to set one up using the [`ThreadPool::new`]-method and task the pool to run jobs using the [`ThreadPool::execute`]-method.
+//! If you need more control over the pool, e.g. over the names of its threads, set it up using a [`ThreadPoolBuilder`] instead.
+//! If you are interested in what the jobs compute, task the pool using the [`ThreadPool::spawn`]-method instead and collect the results from the returned [`JobHandle`]s.
+//! If jobs are supposed to run at the fire times of cron expressions, register them with a [`cron::Scheduler`].
+//! If you want to see which worker ran which job and when, register a [`timeline::Recorder`] with the pool.
+//!
+//! ## Features
+//!
+//! - `prometheus`: The module `metrics::prometheus` renders the metrics of pools in the Prometheus text format and serves them over HTTP.
+//! - `tracing`: The pool emits [tracing](https://docs.rs/tracing) spans for its threads and events for the jobs it runs, and jobs run inside the span they have been ordered in.
 //!
 //! # Examples
 //!
@@ -64,22 +73,32 @@
 //! }
 //! ```
 
+pub mod cron;
+pub mod metrics;
+pub mod timeline;
+
 mod thread {
     //! This module is a wrapper for parts of the module [`std::thread`] to deal with ownership issues when joining threads embedded into a larger data structure.
     //! It lets you spawn threads returning a handle which you can join in the usual way even if the handle is part of a larger data structure.
 
+    use std::io;
     use std::thread;
 
     /// Wraps [`std::thread::JoinHandle<T>`] to set up a thread-counterfeiting heist.
     pub type JoinHandle = Option<thread::JoinHandle<()>>;
 
-    /// Wraps [`std::thread::spawn`] in a [`Option::Some`].
+    /// Wraps [`std::thread::Builder::spawn`] in a [`Option::Some`].
+    /// - `builder` configures the thread to be spawned.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the operating system fails to create the thread.
     #[inline]
-    pub fn spawn<F>(f: F) -> JoinHandle
+    pub fn spawn<F>(builder: thread::Builder, f: F) -> io::Result<JoinHandle>
     where
         F: FnOnce() + Send + 'static,
     {
-        Some(thread::spawn(f))
+        builder.spawn(f).map(Some)
     }
 
     /// Carries out the thread-counterfeiting heist on the thread embedded at the call site to pass it to [`std::thread::JoinHandle<T>::join`].
@@ -106,13 +125,18 @@ mod thread {
         use super::*;
 
         #[test]
+        #[allow(clippy::redundant_pattern_matching)]
         fn test_spawn() {
-            assert!(matches!(spawn(|| {}), Some(_)));
+            assert!(matches!(
+                spawn(thread::Builder::new(), || {}).unwrap(),
+                Some(_)
+            ));
         }
 
         #[test]
+        #[allow(clippy::redundant_pattern_matching)]
         fn test_join() {
-            let mut thread = spawn(|| {});
+            let mut thread = spawn(thread::Builder::new(), || {}).unwrap();
             join(&mut thread);
             assert!(matches!(thread, None));
         }
@@ -120,7 +144,7 @@ mod thread {
         #[test]
         #[should_panic]
         fn test_join_panic_some() {
-            join(&mut spawn(|| panic!("Oh no!")));
+            join(&mut spawn(thread::Builder::new(), || panic!("Oh no!")).unwrap());
         }
 
         #[test]
@@ -133,38 +157,665 @@ mod thread {
 
 use thread::JoinHandle;
 
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
 use std::fmt;
-use std::panic::UnwindSafe;
+use std::io;
+use std::marker::PhantomData;
+use std::panic::{AssertUnwindSafe, UnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use metrics::{JobTimes, Latencies};
 
 use crossbeam::channel::unbounded as channel;
-use crossbeam::channel::Sender;
+use crossbeam::channel::{bounded, Receiver, Select, SendError, Sender, TrySendError};
+use crossbeam::utils::Backoff;
 
 /// Types the jobs the [`ThreadPool`] can run.
-type Job = Box<dyn FnOnce() + UnwindSafe + Send + 'static>;
+pub type Job = Box<dyn FnOnce() + UnwindSafe + Send + 'static>;
+
+/// [`Task`]s are jobs as they are passed around inside the [`ThreadPool`].
+///
+/// In contrast to a [`Job`], a task can be turned back into the closure it has been made of.
+trait Task: UnwindSafe + Send + 'static {
+    /// Runs the task.
+    /// - `worker` is the worker running the task (if it is run by one).
+    fn run(self: Box<Self>, worker: Option<StaffNumber>);
+
+    /// Turns the task back into the closure it has been made of.
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
+
+    /// Tells whether the task has been withdrawn from the job queue by a cancellation.
+    ///
+    /// It is called exactly once when the task is taken out of the job queue.
+    fn withdrawn(&self) -> bool {
+        false
+    }
+}
+
+impl<F> Task for F
+where
+    F: FnOnce() + UnwindSafe + Send + 'static,
+{
+    fn run(self: Box<Self>, _worker: Option<StaffNumber>) {
+        (*self)();
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
+    }
+}
+
+/// [`WorkerAware`] is a task which wants to know the worker running it.
+struct WorkerAware<F>(F);
+
+impl<F> Task for WorkerAware<F>
+where
+    F: FnOnce(Option<StaffNumber>) + UnwindSafe + Send + 'static,
+{
+    fn run(self: Box<Self>, worker: Option<StaffNumber>) {
+        (self.0)(worker);
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self
+    }
+}
+
+/// [`Withdrawal`] settles whether a cancellable job is taken out of the job queue by the supervisor or by a cancellation.
+///
+/// A job withdrawn by a cancellation leaves a stub in the queue which is not counted as queued job and whose slot may be taken over by a new job.
+struct Withdrawal {
+    /// whether it has been settled
+    settled: AtomicBool,
+    /// place where the stubs left in the queue are accounted for
+    noticeboard: Arc<Noticeboard>,
+}
+
+impl Withdrawal {
+    /// Withdraws the job on behalf of a cancellation unless it has been taken out of the queue already.
+    fn withdraw(&self) {
+        if !self.settled.swap(true, AtomicOrdering::SeqCst) {
+            self.noticeboard
+                .withdrawn
+                .fetch_add(1, AtomicOrdering::SeqCst);
+            self.noticeboard
+                .spare_slots
+                .fetch_add(1, AtomicOrdering::SeqCst);
+        }
+    }
+
+    /// Takes the job out of the queue and tells whether it has been withdrawn already.
+    fn dequeue(&self) -> bool {
+        let withdrawn = self.settled.swap(true, AtomicOrdering::SeqCst);
+        if withdrawn {
+            self.noticeboard
+                .withdrawn
+                .fetch_sub(1, AtomicOrdering::SeqCst);
+        }
+        withdrawn
+    }
+}
+
+/// [`Cancellable`] is a task which may be withdrawn from the job queue by a cancellation.
+struct Cancellable {
+    /// the task
+    task: Box<dyn Task>,
+    /// who takes the task out of the job queue
+    withdrawal: Arc<Withdrawal>,
+}
+
+impl Task for Cancellable {
+    fn run(self: Box<Self>, worker: Option<StaffNumber>) {
+        self.task.run(worker);
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self.task.into_any()
+    }
+
+    fn withdrawn(&self) -> bool {
+        self.withdrawal.dequeue()
+    }
+}
+
+/// [`Timed`] is a task which records how long it waited to be run and how long it ran.
+struct Timed {
+    /// the task
+    task: Box<dyn Task>,
+    /// when the task has been ordered
+    ordered: Instant,
+    /// place to record the latencies
+    times: Arc<JobTimes>,
+}
+
+impl Timed {
+    /// Wraps `task` to record its latencies (if they are recorded at all).
+    /// - `task` is the task to be wrapped.
+    /// - `ordered` is when the task is supposed to wait for a worker from on.
+    /// - `times` is the place to record the latencies.
+    fn wrap(task: Box<dyn Task>, ordered: Instant, times: &Option<Arc<JobTimes>>) -> Box<dyn Task> {
+        match times {
+            Some(times) => Box::new(Self {
+                task,
+                ordered,
+                times: Arc::clone(times),
+            }),
+            None => task,
+        }
+    }
+}
+
+impl Task for Timed {
+    fn run(self: Box<Self>, worker: Option<StaffNumber>) {
+        let Self {
+            task,
+            ordered,
+            times,
+        } = *self;
+
+        let started = times.start(ordered);
+        let outcome = std::panic::catch_unwind(AssertUnwindSafe(move || task.run(worker)));
+        // the run time of a panicked task is recorded as well
+        times.finish(started);
+
+        if let Err(payload) = outcome {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self.task.into_any()
+    }
+
+    fn withdrawn(&self) -> bool {
+        self.task.withdrawn()
+    }
+}
+
+/// [`Traced`] is a task which is run inside the span it has been ordered in.
+#[cfg(feature = "tracing")]
+struct Traced {
+    /// the task
+    task: Box<dyn Task>,
+    /// the span the task has been ordered in
+    span: AssertUnwindSafe<tracing::Span>,
+}
+
+#[cfg(feature = "tracing")]
+impl Traced {
+    /// Wraps `task` to run inside `span`.
+    /// - `task` is the task to be wrapped.
+    /// - `span` is the span the task has been ordered in.
+    fn wrap(task: Box<dyn Task>, span: tracing::Span) -> Box<dyn Task> {
+        Box::new(Self {
+            task,
+            span: AssertUnwindSafe(span),
+        })
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Task for Traced {
+    fn run(self: Box<Self>, worker: Option<StaffNumber>) {
+        let Self { task, span } = *self;
+        span.in_scope(move || task.run(worker));
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
+        self.task.into_any()
+    }
+
+    fn withdrawn(&self) -> bool {
+        self.task.withdrawn()
+    }
+}
+
+/// Types the way the payload of a panicked job is handed to whoever waits for the job's result.
+type Handoff = Box<dyn FnOnce(Box<dyn Any + Send>) + Send>;
+
+/// [`Panicked`] is what a job someone waits for panics with: the original payload along with the way to hand it over.
+///
+/// This way the supervisor gets to see the original payload before it is handed over.
+struct Panicked {
+    /// the original payload
+    payload: Box<dyn Any + Send>,
+    /// the way to hand the payload over
+    handoff: Handoff,
+}
+
+impl Panicked {
+    /// Splits `payload` into the original payload and the way to hand it over (if someone waits for it).
+    fn split(payload: Box<dyn Any + Send>) -> (Box<dyn Any + Send>, Option<Handoff>) {
+        match payload.downcast::<Self>() {
+            Ok(panicked) => (panicked.payload, Some(panicked.handoff)),
+            Err(payload) => (payload, None),
+        }
+    }
+}
+
+/// Runs `task` outside of a worker, that is, hands the payload of a panic to whoever waits for it or re-raises it otherwise.
+fn run_unsupervised(task: Box<dyn Task>) {
+    if let Err(payload) = std::panic::catch_unwind(AssertUnwindSafe(move || task.run(None))) {
+        match Panicked::split(payload) {
+            (payload, Some(handoff)) => handoff(payload),
+            (payload, None) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// Turns `task` into a [`Job`] to hand it out of the pool.
+fn into_job(task: Box<dyn Task>) -> Job {
+    Box::new(move || run_unsupervised(task))
+}
 
 /// Defines what the [`ThreadPool`] can be ordered to do.
 enum Message {
     /// Order the pool to execute a job.
-    NewJob(Job),
-    /// Order the pool to finish its remaining jobs and shut down afterwards.
+    NewJob(Box<dyn Task>),
+    /// Order the pool to execute a job when the given time has come.
+    Schedule(Instant, Delayed),
+    /// Order the pool to forget about the delayed job with the given id.
+    Cancel(u64),
+    /// Order the pool to report back once all jobs ordered so far are done.
+    Join(Sender<()>),
+    /// Order the pool to employ the given number of workers and to report back the resulting size.
+    Resize(usize, Sender<Result<usize, Error>>),
+    /// Order the pool to shut down as specified and to send a report afterwards.
+    Shutdown(ShutdownMode, Sender<ShutdownReport>),
+    /// Order a worker to shut down.
     Terminate,
 }
 
 impl fmt::Display for Message {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
+        match self {
             Self::NewJob(_) => write!(f, "[NewJob]"),
+            Self::Schedule(_, _) => write!(f, "[Schedule]"),
+            Self::Cancel(_) => write!(f, "[Cancel]"),
+            Self::Join(_) => write!(f, "[Join]"),
+            Self::Resize(_, _) => write!(f, "[Resize]"),
+            Self::Shutdown(_, _) => write!(f, "[Shutdown]"),
             Self::Terminate => write!(f, "[Terminate]"),
         }
     }
 }
 
+/// Lists what can go wrong when dealing with a [`ThreadPool`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A pool without worker-threads was requested.
+    ZeroWorkers,
+    /// The pool has been shut down and does not accept jobs anymore.
+    PoolShutDown,
+    /// The job queue of the pool is full (see [`RejectionPolicy::Reject`]).
+    QueueFull,
+    /// The operating system failed to create a thread for the pool.
+    SpawnFailed(io::Error),
+    /// The fallback of the restart intensity would respawn worker-threads (see [`ThreadPoolBuilder::restart_intensity`]).
+    InvalidFallback,
+    /// A job queue without room was requested (see [`ThreadPoolBuilder::queue_capacity`]).
+    ZeroCapacity,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ZeroWorkers => write!(f, "Setting up a pool with no workers is not allowed."),
+            Self::PoolShutDown => write!(f, "Pool has been shut down."),
+            Self::QueueFull => write!(f, "Job queue is full."),
+            Self::SpawnFailed(e) => write!(f, "Spawning a thread failed: {}", e),
+            Self::InvalidFallback => write!(f, "Falling back must not respawn worker-threads."),
+            Self::ZeroCapacity => write!(
+                f,
+                "Setting up a pool with a queue without room is not allowed."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::SpawnFailed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// [`ExecuteError`]s are returned by [`ThreadPool::try_execute`] and hand back the job which has been rejected.
+pub struct ExecuteError<F> {
+    /// the rejected job
+    job: F,
+    /// the reason for the rejection
+    error: Error,
+}
+
+impl<F> ExecuteError<F> {
+    /// Tells why the job has been rejected.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// Hands back the rejected job.
+    pub fn into_job(self) -> F {
+        self.job
+    }
+}
+
+impl<F> fmt::Debug for ExecuteError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExecuteError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> fmt::Display for ExecuteError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rejected job: {}", self.error)
+    }
+}
+
+impl<F> std::error::Error for ExecuteError<F> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Configures what the [`ThreadPool`] is supposed to do with a job which is ordered when its job queue is full (see [`ThreadPoolBuilder::queue_capacity`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectionPolicy {
+    /// Configure the pool to block the ordering thread until there is room in the queue.
+    Block,
+    /// Configure the pool to reject the job.
+    ///
+    /// Only [`ThreadPool::try_execute`] and its variants hand the rejected job back while the other ways to order jobs panic (see [`ThreadPool::execute`]).
+    Reject,
+    /// Configure the pool to run the job on the ordering thread.
+    CallerRuns,
+    /// Configure the pool to discard the oldest job of the lowest priority in the queue to make room for the job.
+    /// The discarded jobs are counted by [`PoolStats::evicted_jobs`].
+    DropOldest,
+}
+
+/// Ranks the jobs in the job queue of a [`ThreadPool`] (see [`ThreadPool::execute_with_priority`]).
+///
+/// Idle workers are always handed the pending job of highest priority unless a job of lower priority has been passed over too often (see [`ThreadPoolBuilder::aging`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Rank a job below the default, e.g. for background work.
+    Low = 0,
+    /// Rank a job as by default.
+    Normal = 1,
+    /// Rank a job above the default, e.g. for latency-critical work.
+    High = 2,
+}
+
+impl Priority {
+    /// number of priority levels
+    const LEVELS: usize = 3;
+}
+
 /// Configures what the [`ThreadPool`] is supposed to do in case of a 'panicking job', that is, a job which panics while running in a thread.
 pub enum PanicSwitch {
     /// Configure the pool to finish parallely running jobs and then kill the whole process in case of a panicked job.
     Kill,
     /// Configure the pool to ignore panicked jobs and just respawn the polluted threads.
     Respawn,
+    /// Configure the pool to stop accepting jobs in case of a panicked job, to finish parallely running jobs and to discard the others.
+    /// The discarded jobs are dropped right away, so they are never part of a [`ShutdownReport`].
+    /// The first panic is then re-raised on the thread which joins, shuts down or drops the pool (see [`ThreadPool::join`]).
+    Propagate,
+}
+
+impl PanicPolicy for PanicSwitch {
+    fn on_panic(&mut self, _worker: StaffNumber, _payload: &(dyn Any + Send)) -> PanicDecision {
+        match self {
+            Self::Kill => PanicDecision::Abort,
+            Self::Respawn => PanicDecision::Respawn,
+            Self::Propagate => PanicDecision::Propagate,
+        }
+    }
+}
+
+/// Tells the [`ThreadPool`] what to do about a panicked job (see [`PanicPolicy`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PanicDecision {
+    /// Respawn the polluted thread and carry on.
+    Respawn,
+    /// Retire the polluted thread without replacement, that is, let the pool have one worker-thread less (see [`ThreadPool::resize`]).
+    /// The last worker-thread is never retired: the decision is taken as [`PanicDecision::Close`] then.
+    Retire,
+    /// Shut down the pool gracefully, that is, stop accepting jobs, finish the remaining ones and dismiss the worker-threads afterwards.
+    ///
+    /// The polluted thread is not respawned and delayed jobs are dealt with according to the [`TimerShutdown`] of the pool.
+    /// Note that the owner still has to shut down or drop the pool which then only collects the [`ShutdownReport`] (see [`ThreadPool::shutdown`]).
+    Close,
+    /// Stop accepting jobs, finish parallely running jobs, discard the others and re-raise the panic (see [`PanicSwitch::Propagate`]).
+    Propagate,
+    /// Finish parallely running jobs and then kill the whole process (see [`PanicSwitch::Kill`]).
+    Abort,
+}
+
+/// [`PanicPolicy`]s decide what the [`ThreadPool`] does about a panicked job (see [`ThreadPoolBuilder::panic_policy`]).
+///
+/// The [`PanicSwitch`] is the built-in policy which always comes to the same decision.
+/// A policy is consulted by the supervisor-thread and is thus supposed to decide quickly.
+/// Note that a panicking policy is taken as a decision to abort.
+///
+/// # Examples
+///
+/// Setting up a pool which respawns its worker-threads unless they are out of memory:
+///
+/// ```
+/// use poolio::{PanicDecision, PanicPolicy, StaffNumber};
+/// use std::any::Any;
+///
+/// struct OutOfMemory;
+///
+/// struct Policy;
+///
+/// impl PanicPolicy for Policy {
+///     fn on_panic(&mut self, _worker: StaffNumber, payload: &(dyn Any + Send)) -> PanicDecision {
+///         if payload.is::<OutOfMemory>() {
+///             PanicDecision::Retire
+///         } else {
+///             PanicDecision::Respawn
+///         }
+///     }
+/// }
+///
+/// let pool = poolio::ThreadPoolBuilder::new(2)
+///     .panic_policy(Policy)
+///     .build()
+///     .unwrap();
+/// pool.execute(|| std::panic::panic_any(OutOfMemory));
+/// pool.join();
+/// assert_eq!(1, pool.size());
+/// ```
+pub trait PanicPolicy: Send + 'static {
+    /// Decides what to do about a panicked job.
+    /// - `worker` is the worker which ran the job.
+    /// - `payload` is what the job panicked with.
+    fn on_panic(&mut self, worker: StaffNumber, payload: &(dyn Any + Send)) -> PanicDecision;
+}
+
+/// Configures how the [`ThreadPool`] is supposed to deal with the jobs which have not been started yet when it is shut down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShutdownMode {
+    /// Configure the pool to finish all remaining jobs.
+    Graceful,
+    /// Configure the pool to start remaining jobs until the given time from now on has passed and to discard the others.
+    Deadline(Duration),
+    /// Configure the pool to discard all remaining jobs.
+    Immediate,
+}
+
+/// Configures what the [`ThreadPool`] is supposed to do with delayed jobs which are not due yet when it is shut down (see [`ThreadPool::execute_after`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerShutdown {
+    /// Configure the pool to discard the delayed jobs (reporting them in the [`ShutdownReport`]).
+    Discard,
+    /// Configure the pool to run the delayed jobs right away.
+    Fire,
+    /// Configure the pool to wait until the delayed jobs are due and to run them then.
+    Wait,
+}
+
+/// Configures when the next run of a recurring job is due (see [`ThreadPool::execute_recurring`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cadence {
+    /// Configure the runs to be due a period apart from each other, skipping those which have been missed.
+    FixedRate,
+    /// Configure each run to be due a period after the previous one has finished.
+    FixedDelay,
+}
+
+/// Configures whether a recurring job goes on after it has panicked (see [`ThreadPool::execute_recurring`]).
+///
+/// Note that the panic is dealt with according to the [`PanicSwitch`] of the [`ThreadPool`] in any case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecurringPanic {
+    /// Configure the recurrence to stop after a panicked run.
+    Stop,
+    /// Configure the recurrence to go on after a panicked run.
+    Continue,
+}
+
+/// [`ShutdownReport`]s tell what the [`ThreadPool`] did with the jobs it was ordered to run.
+///
+/// Note that jobs which were running when the pool was ordered to shut down are always finished.
+pub struct ShutdownReport {
+    /// number of jobs which have been finished
+    pub completed: usize,
+    /// number of jobs which have panicked
+    pub panicked: usize,
+    /// jobs which have been discarded without being started
+    /// (delayed jobs first by when they are due, then queued jobs from the highest [`Priority`] to the lowest and in the order they were ordered within a priority)
+    pub discarded: Vec<Job>,
+}
+
+impl fmt::Debug for ShutdownReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ShutdownReport")
+            .field("completed", &self.completed)
+            .field("panicked", &self.panicked)
+            .field("discarded", &self.discarded.len())
+            .finish()
+    }
+}
+
+/// [`PoolStats`] are snapshots of the figures of a [`ThreadPool`] (see [`ThreadPool::stats`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// number of worker-threads the pool is supposed to have at least
+    pub configured_workers: usize,
+    /// number of worker-threads the pool has
+    pub live_workers: usize,
+    /// number of worker-threads waiting for jobs
+    pub idle_workers: usize,
+    /// number of jobs waiting for a worker-thread
+    pub queued_jobs: usize,
+    /// number of jobs being run
+    pub running_jobs: usize,
+    /// number of jobs which have been finished so far
+    pub completed_jobs: usize,
+    /// number of jobs which have panicked so far
+    pub panicked_jobs: usize,
+    /// number of jobs which have been discarded to make room for others so far (see [`RejectionPolicy::DropOldest`])
+    pub evicted_jobs: usize,
+    /// number of worker-threads which have been respawned so far (see [`PanicSwitch::Respawn`])
+    pub respawns: usize,
+    /// whether the pool has exceeded its restart intensity and fallen back (see [`ThreadPoolBuilder::restart_intensity`])
+    pub escalated: bool,
+}
+
+/// [`PoolObserver`]s are told about what happens in a [`ThreadPool`] (see [`ThreadPoolBuilder::observer`]).
+///
+/// All callbacks do nothing by default, so an observer only has to implement those it is interested in.
+/// The callbacks are run on the threads of the pool (or the ordering thread), so they are supposed to be quick.
+/// Note that a panic in a callback is ignored.
+///
+/// # Examples
+///
+/// Setting up a pool which logs panicked jobs:
+///
+/// ```
+/// use poolio::{PoolObserver, StaffNumber};
+/// use std::any::Any;
+///
+/// struct Logger;
+///
+/// impl PoolObserver for Logger {
+///     fn job_panicked(&self, worker: StaffNumber, payload: &(dyn Any + Send)) {
+///         eprintln!("worker {} ran a panicking job: {:?}", worker, payload.downcast_ref::<&str>());
+///     }
+/// }
+///
+/// let pool = poolio::ThreadPoolBuilder::new(2)
+///     .panic_switch(poolio::PanicSwitch::Respawn)
+///     .observer(Logger)
+///     .build()
+///     .unwrap();
+/// pool.execute(|| panic!("Oh no!"));
+/// ```
+pub trait PoolObserver: Send + Sync + 'static {
+    /// A job has been accepted by the pool.
+    ///
+    /// Delayed jobs are accepted when they are due and recurring jobs once for each run.
+    fn job_submitted(&self) {}
+
+    /// Worker `worker` has started a job.
+    fn job_started(&self, _worker: StaffNumber) {}
+
+    /// Worker `worker` has finished a job which ran for `duration`.
+    fn job_finished(&self, _worker: StaffNumber, _duration: Duration) {}
+
+    /// A job run by worker `worker` has panicked with `payload`.
+    fn job_panicked(&self, _worker: StaffNumber, _payload: &(dyn Any + Send)) {}
+
+    /// Worker `worker` has been spawned.
+    fn worker_spawned(&self, _worker: StaffNumber) {}
+
+    /// Worker `worker` has been respawned after a panicked job (see [`PanicSwitch::Respawn`]).
+    fn worker_respawned(&self, _worker: StaffNumber) {}
+
+    /// Worker `worker` has been dismissed.
+    fn worker_dismissed(&self, _worker: StaffNumber) {}
+
+    /// The pool has terminated with the final figures `stats`.
+    fn pool_terminated(&self, _stats: &PoolStats) {}
+}
+
+/// [`Observers`] are the [`PoolObserver`]s registered with a pool.
+#[derive(Clone)]
+struct Observers(Arc<[Arc<dyn PoolObserver>]>);
+
+// observers are only ever called behind `catch_unwind`, so a panic cannot leave them broken unnoticed
+impl std::panic::UnwindSafe for Observers {}
+impl std::panic::RefUnwindSafe for Observers {}
+
+impl Observers {
+    /// Tells whether no observer has been registered.
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Tells every observer about an event (ignoring panics).
+    /// - `event` is the callback telling an observer about the event.
+    fn notify<E>(&self, event: E)
+    where
+        E: Fn(&dyn PoolObserver),
+    {
+        for observer in self.0.iter() {
+            let _ = std::panic::catch_unwind(AssertUnwindSafe(|| event(observer.as_ref())));
+        }
+    }
 }
 
 /// Abstracts the thread-pools.
@@ -180,7 +831,9 @@ impl ThreadPool {
     ///
     /// # Errors
     ///
-    /// An error is returned if 0 was passed as `size` (since a pool without worker-threads does not make sense).
+    /// An error is returned if
+    /// 1. 0 was passed as `size` (since a pool without worker-threads does not make sense).
+    /// 2. a thread could not be spawned.
     ///
     /// # Examples
     ///
@@ -189,25 +842,22 @@ impl ThreadPool {
     /// ```
     /// let pool = poolio::ThreadPool::new(3, poolio::PanicSwitch::Kill).unwrap();
     /// ```
-    pub fn new<'a>(size: usize, mode: PanicSwitch) -> Result<Self, &'a str> {
-        if size == 0 {
-            return Err("Setting up a pool with no workers is not allowed.");
-        };
-
-        let pool = Self {
-            supervisor: Supervisor::new(size, mode),
-        };
-        Ok(pool)
+    pub fn new(size: usize, mode: PanicSwitch) -> Result<Self, Error> {
+        ThreadPoolBuilder::new(size).panic_switch(mode).build()
     }
 
     /// Runs a job in `self`.
     /// - `f` is the job to be run and has to be provided as a certain closure.
     ///
     /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self`.
+    /// If the job queue of `self` is full, the behavior is according to its [`RejectionPolicy`].
     ///
     /// # Panics
     ///
-    /// A panic is caused if the pool is unreachable.
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
     ///
     /// # Examples
     ///
@@ -222,269 +872,4758 @@ impl ThreadPool {
     where
         F: FnOnce() + UnwindSafe + Send + 'static,
     {
-        let job = Box::new(f);
-
-        self.send(Message::NewJob(job));
+        self.execute_with_priority(Priority::Normal, f);
     }
 
-    /// Tries to shut down `self` gracefully.
+    /// Runs a job in `self` with a certain priority.
+    /// - `priority` is the rank of the job in the job queue.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
     ///
-    /// In particular, one has to assume that all remaining jobs will be finished (modulo panics in [`PanicSwitch::Kill`]-mode).
+    /// Apart from the priority, it is just like [`ThreadPool::execute`] which runs jobs with [`Priority::Normal`].
     ///
     /// # Panics
     ///
-    /// A panic occurs if
+    /// A panic is caused if
     /// 1. the pool is unreachable.
-    /// 2. joining the threads panics.
-    fn terminate(&mut self) {
-        self.send(Message::Terminate);
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and ordering background work and a request:
+    ///
+    /// ```
+    /// use poolio::Priority;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// pool.execute_with_priority(Priority::Low, || println!{"cleaning up"});
+    /// pool.execute_with_priority(Priority::High, || println!{"answering request"});
+    /// ```
+    pub fn execute_with_priority<F>(&self, priority: Priority, f: F)
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.supervisor.inlet.execute(priority, Box::new(f));
+    }
 
-        thread::join(&mut self.supervisor.thread);
+    /// Tries to run a job in `self`.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self`.
+    /// If the job queue of `self` is full, the behavior is according to its [`RejectionPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// An error handing back `f` is returned if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and running a job in it or - if that fails - right here:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// if let Err(e) = pool.try_execute(|| println!{"house"}) {
+    ///     let job = e.into_job();
+    ///     job();
+    /// }
+    /// ```
+    pub fn try_execute<F>(&self, f: F) -> Result<(), ExecuteError<F>>
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.try_execute_with_priority(Priority::Normal, f)
     }
 
-    /// Wraps sending a [`Message`] to the pool.
+    /// Tries to run a job in `self` with a certain priority.
+    /// - `priority` is the rank of the job in the job queue.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
     ///
-    /// # Panics
+    /// Apart from the priority, it is just like [`ThreadPool::try_execute`] which runs jobs with [`Priority::Normal`].
     ///
-    /// A panic is caused if the receiver has already been deallocated.
-    fn send(&self, msg: Message) {
-        let panic_message = format!("Ordering {} failed. Pool is unreachable.", msg);
+    /// # Errors
+    ///
+    /// An error handing back `f` is returned if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    pub fn try_execute_with_priority<F>(
+        &self,
+        priority: Priority,
+        f: F,
+    ) -> Result<(), ExecuteError<F>>
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.supervisor.inlet.try_execute(priority, f)
+    }
 
-        self.supervisor.orders_s.send(msg).expect(&panic_message);
+    /// Tells how many jobs of any priority are waiting in the job queue of `self`.
+    ///
+    /// It is the same figure as [`PoolStats::queued_jobs`], so it includes the delayed jobs which are due but not started yet.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and checking that its queue is empty:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// assert_eq!(0, pool.queued_len());
+    /// ```
+    pub fn queued_len(&self) -> usize {
+        self.supervisor.inlet.queued_len()
     }
-}
 
-impl Drop for ThreadPool {
-    /// Tries to shut down `self` gracefully.
+    /// Runs a job in `self` once a certain amount of time has passed and hands out a [`Timer`] to cancel it until then.
+    /// - `delay` is the time to wait before the job is run.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
     ///
-    /// In particular, one has to assume that all remaining jobs will be finished (modulo panics in [`PanicSwitch::Kill`]-mode).
+    /// When the job is due, it is queued with [`Priority::Normal`] like a job ordered by [`ThreadPool::execute`].
+    /// Note that [`ThreadPool::join`] does not wait for jobs which are not due yet and that on shutdown those jobs are dealt with according to the [`TimerShutdown`] of `self`.
     ///
     /// # Panics
     ///
-    /// A panic occurs if
-    /// 1. the pool is unreachable
-    /// 2. joining the threads panics.
+    /// A panic is caused if the pool is unreachable.
     ///
-    /// Remember that a panic while dropping aborts the whole process.
-    fn drop(&mut self) {
-        self.terminate();
+    /// # Examples
+    ///
+    /// Setting up a pool and ordering a reminder which is cancelled before it is due:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let timer = pool.execute_after(Duration::from_secs(60), || println!{"reminder"});
+    /// assert!(timer.cancel());
+    /// ```
+    pub fn execute_after<F>(&self, delay: Duration, f: F) -> Timer
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.execute_at(Instant::now() + delay, f)
     }
-}
-
-/// [`StaffNumber`]s identify workers.
-type StaffNumber = usize;
 
-/// [`Status`] is what worker with [`StaffNumber`] is currently doing.
-enum Status {
-    /// worker `id` is idle.
-    Idle(StaffNumber),
-    /// worker `id` has a panicked job.
-    Panic(StaffNumber),
+    /// Runs a job in `self` at a certain point in time and hands out a [`Timer`] to cancel it until then.
+    /// - `at` is when the job is due.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// It is just like [`ThreadPool::execute_after`] but with an absolute point in time.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the pool is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and printing a string once a tenth of a second has passed:
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let timer = pool.execute_at(Instant::now() + Duration::from_millis(100), || println!{"later"});
+    /// assert!(timer.is_pending());
+    /// ```
+    pub fn execute_at<F>(&self, at: Instant, f: F) -> Timer
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        let timer = self.timer();
+
+        let task: Box<dyn Task> = Box::new(f);
+        // the job continues the trace it has been ordered in
+        #[cfg(feature = "tracing")]
+        let task = Traced::wrap(task, tracing::Span::current());
+
+        self.send(Message::Schedule(
+            at,
+            Delayed {
+                id: timer.id,
+                state: Arc::clone(&timer.state),
+                recurring: false,
+                // the job waits for a worker from when it is due on
+                task: Timed::wrap(task, at, &self.supervisor.inlet.times),
+            },
+        ));
+
+        timer
+    }
+
+    /// Runs a job in `self` over and over again at a fixed rate and hands out a [`Timer`] to stop it.
+    /// - `period` is the time between two runs.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// It is just like [`ThreadPool::execute_recurring`] with [`Cadence::FixedRate`] and [`RecurringPanic::Stop`].
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the pool is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and counting ticks until the recurrence is stopped:
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    ///
+    /// let ticks = Arc::new(AtomicUsize::new(0));
+    /// let ticks_ref = Arc::clone(&ticks);
+    /// let timer = pool.execute_every(Duration::from_millis(10), move || {
+    ///     ticks_ref.fetch_add(1, Ordering::SeqCst);
+    /// });
+    ///
+    /// while ticks.load(Ordering::SeqCst) < 3 {
+    ///     std::thread::sleep(Duration::from_millis(10));
+    /// }
+    /// assert!(timer.cancel());
+    /// ```
+    pub fn execute_every<F>(&self, period: Duration, f: F) -> Timer
+    where
+        F: FnMut() + UnwindSafe + Send + 'static,
+    {
+        self.execute_recurring(period, Cadence::FixedRate, RecurringPanic::Stop, f)
+    }
+
+    /// Runs a job in `self` over and over again and hands out a [`Timer`] to stop it.
+    /// - `period` is the time between two runs as specified by `cadence`.
+    /// - `cadence` specifies when the next run is due.
+    /// - `on_panic` specifies whether the recurrence goes on after a panicked run.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// The first run is due a period from now on and each run is queued like a job ordered by [`ThreadPool::execute_after`].
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self`.
+    /// On shutdown, the next run is dealt with according to the [`TimerShutdown`] of `self` and the recurrence stops afterwards.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the pool is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and refreshing a cache a tenth of a second after each refresh, even if a refresh panics:
+    ///
+    /// ```
+    /// use poolio::{Cadence, RecurringPanic};
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Respawn).unwrap();
+    /// let timer = pool.execute_recurring(
+    ///     Duration::from_millis(100),
+    ///     Cadence::FixedDelay,
+    ///     RecurringPanic::Continue,
+    ///     || println!("refreshing"),
+    /// );
+    /// assert!(timer.is_pending());
+    /// ```
+    pub fn execute_recurring<F>(
+        &self,
+        period: Duration,
+        cadence: Cadence,
+        on_panic: RecurringPanic,
+        f: F,
+    ) -> Timer
+    where
+        F: FnMut() + UnwindSafe + Send + 'static,
+    {
+        let timer = self.timer();
+
+        let due = Instant::now() + period;
+        let recurring = Recurring {
+            f,
+            due,
+            period,
+            cadence,
+            on_panic,
+            id: timer.id,
+            state: Arc::clone(&timer.state),
+            orders_s: self.supervisor.inlet.orders_s.clone(),
+            times: self.supervisor.inlet.times.clone(),
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        };
+
+        self.send(Message::Schedule(due, recurring.into_delayed()));
+
+        timer
+    }
+
+    /// Sets up a [`Timer`] for a new delayed job.
+    fn timer(&self) -> Timer {
+        Timer {
+            id: self
+                .supervisor
+                .inlet
+                .timer_ids
+                .fetch_add(1, AtomicOrdering::Relaxed),
+            state: Arc::new(AtomicU8::new(Delayed::PENDING)),
+            orders_s: self.supervisor.inlet.orders_s.clone(),
+        }
+    }
+
+    /// Runs a job in `self` and hands out a [`JobHandle`] to get hold of the job's result.
+    /// - `f` is the job to be run and has to be provided as a certain closure returning a value.
+    ///
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self` as with [`ThreadPool::execute`].
+    /// The panic is reported to the handle once the pool has dealt with it, even in [`PanicSwitch::Kill`]-mode before the process is killed.
+    /// Only if the pool re-raises the panic itself (see [`PanicSwitch::Propagate`]), the handle gets a [`&str`] saying so instead of the original payload.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and computing a sum in it:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Respawn).unwrap();
+    /// let handle = pool.spawn(|| (1..=10).sum::<usize>());
+    /// assert_eq!(55, handle.join().unwrap());
+    /// ```
+    pub fn spawn<F, T>(&self, f: F) -> JobHandle<T>
+    where
+        F: FnOnce() -> T + UnwindSafe + Send + 'static,
+        T: Send + 'static,
+    {
+        let (result_s, result_r) = bounded(1);
+
+        self.execute(move || match std::panic::catch_unwind(f) {
+            Ok(value) => {
+                // the handle may have been dropped which is not an error
+                let _ = result_s.send(Ok(value));
+            }
+            Err(payload) => {
+                // the supervisor gets to see the original payload before it is handed to the handle
+                let handoff: Handoff = Box::new(move |payload| {
+                    // the handle may have been dropped which is not an error
+                    let _ = result_s.send(Err(payload));
+                });
+                std::panic::resume_unwind(Box::new(Panicked { payload, handoff }));
+            }
+        });
+
+        JobHandle { result_r }
+    }
+
+    /// Runs a job in `self` which can be cancelled and hands out a [`CancelHandle`] to do so.
+    /// - `f` is the job to be run and has to be provided as a certain closure taking a [`CancellationToken`].
+    ///
+    /// A job which is cancelled before it has started is not run at all (and dropped right away).
+    /// A job which is cancelled while it is running is told so by its token which it is supposed to poll.
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self` as with [`ThreadPool::execute`].
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and cancelling a long-running job:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let handle = pool.execute_cancellable(|token| {
+    ///     while !token.is_cancelled() {
+    ///         std::thread::sleep(Duration::from_millis(1));
+    ///     }
+    /// });
+    ///
+    /// while handle.status() == poolio::JobStatus::Queued {
+    ///     std::thread::yield_now();
+    /// }
+    /// assert!(handle.cancel());
+    /// assert_eq!(poolio::JobStatus::CancelledWhileRunning, handle.wait());
+    /// ```
+    pub fn execute_cancellable<F>(&self, f: F) -> CancelHandle
+    where
+        F: FnOnce(&CancellationToken) + UnwindSafe + Send + 'static,
+    {
+        self.supervisor
+            .inlet
+            .execute_with_context(None, move |ctx| f(ctx.token()))
+    }
+
+    /// Runs a job in `self` which is given a [`JobContext`] and hands out a [`CancelHandle`] to cancel it.
+    /// - `f` is the job to be run and has to be provided as a certain closure taking a context.
+    ///
+    /// The context tells the job which worker runs it, identifies the job and lets it check whether it has been cancelled (as with [`ThreadPool::execute_cancellable`]) as well as order follow-up jobs.
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of `self` as with [`ThreadPool::execute`].
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and running a job which logs who runs it and orders a follow-up job:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// pool.execute_with_context(|ctx| {
+    ///     println!("job {} on worker {:?}", ctx.job_id(), ctx.worker_id());
+    ///     ctx.pool().execute(|| println!("follow-up"));
+    /// });
+    /// pool.join();
+    /// ```
+    pub fn execute_with_context<F>(&self, f: F) -> CancelHandle
+    where
+        F: FnOnce(&JobContext) + UnwindSafe + Send + 'static,
+    {
+        self.supervisor.inlet.execute_with_context(None, f)
+    }
+
+    /// Runs a job in `self` which is given a [`JobContext`] with a deadline and hands out a [`CancelHandle`] to cancel it.
+    /// - `deadline` is when the job is supposed to be done.
+    /// - `f` is the job to be run and has to be provided as a certain closure taking a context.
+    ///
+    /// It is just like [`ThreadPool::execute_with_context`] but the context tells the deadline.
+    /// Note that it is up to the job to honor the deadline.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and running a job which gives up when its time is up:
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let deadline = Instant::now() + Duration::from_millis(10);
+    /// let handle = pool.execute_with_deadline(deadline, |ctx| {
+    ///     while !ctx.is_overdue() {
+    ///         std::thread::yield_now();
+    ///     }
+    /// });
+    /// assert_eq!(poolio::JobStatus::Completed, handle.wait());
+    /// ```
+    pub fn execute_with_deadline<F>(&self, deadline: Instant, f: F) -> CancelHandle
+    where
+        F: FnOnce(&JobContext) + UnwindSafe + Send + 'static,
+    {
+        self.supervisor
+            .inlet
+            .execute_with_context(Some(deadline), f)
+    }
+
+    /// Hands out a [`PoolHandle`] to order jobs in `self` from elsewhere, e.g. from jobs.
+    ///
+    /// Note that the handle does not keep `self` from shutting down.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and ordering a job through a handle:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let handle = pool.handle();
+    /// pool.execute(move || handle.execute(|| println!("nested")));
+    /// pool.join();
+    /// ```
+    pub fn handle(&self) -> PoolHandle {
+        PoolHandle {
+            inlet: self.supervisor.inlet.clone(),
+        }
+    }
+
+    /// Runs jobs in `self` which may borrow data from the call site.
+    /// - `f` is given a [`Scope`] to run the borrowing jobs in.
+    ///
+    /// All jobs run in the scope are finished before this method returns (in the spirit of [`std::thread::scope`]).
+    /// Note that if a job panics, the behavior is according to the setting of the [`PanicSwitch`] of `self`.
+    /// In particular, the panic is only surfaced here if the pool is not in [`PanicSwitch::Kill`]-mode (which ends the process anyway).
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. `f` panics (after all jobs have finished).
+    /// 3. a job run in the scope has panicked or has been discarded (after all jobs have finished).
+    ///    The panic of the first panicked job is re-raised unless the pool keeps it to re-raise it itself (in [`PanicSwitch::Propagate`]-mode).
+    ///
+    /// Remember that calling this method from within a job blocks a worker of `self` until the scope has finished.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and summing up the halves of a vector concurrently:
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    ///
+    /// let numbers: Vec<usize> = (1..=10).collect();
+    /// let sum = AtomicUsize::new(0);
+    ///
+    /// pool.scope(|s| {
+    ///     for half in numbers.chunks(5) {
+    ///         let sum = &sum;
+    ///         s.execute(move || {
+    ///             sum.fetch_add(half.iter().sum(), Ordering::SeqCst);
+    ///         });
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(55, sum.into_inner());
+    /// ```
+    pub fn scope<'env, F, T>(&self, f: F) -> T
+    where
+        F: for<'scope> FnOnce(&'scope Scope<'scope, 'env>) -> T,
+    {
+        // this channel is used by the scoped jobs to report that they are done and whether they have panicked
+        let (outcomes_s, outcomes_r) = channel();
+
+        let scope = Scope {
+            pool: self,
+            outcomes_s,
+            jobs: AtomicUsize::new(0),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| f(&scope)));
+
+        // wait for all scoped jobs to be done (including the ones run by scoped jobs)
+        let mut done_jobs = 0;
+        let mut panicked_jobs = 0;
+        let mut first_panic = None;
+        while done_jobs < scope.jobs.load(AtomicOrdering::SeqCst) {
+            if let Outcome::Panicked(payload) = outcomes_r.recv().unwrap() {
+                panicked_jobs += 1;
+                first_panic = first_panic.or(payload);
+            }
+            done_jobs += 1;
+        }
+
+        match (result, first_panic) {
+            (Err(payload), _) | (Ok(_), Some(payload)) => std::panic::resume_unwind(payload),
+            (Ok(_), None) if panicked_jobs > 0 => panic!("{} scoped jobs panicked.", panicked_jobs),
+            (Ok(value), None) => value,
+        }
+    }
+
+    /// Waits for all jobs which have been ordered so far to be finished.
+    ///
+    /// In contrast to dropping `self`, the pool can be used again afterwards.
+    /// Note that a job which is still running has not been finished even if it orders new jobs itself.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. a job has panicked in [`PanicSwitch::Propagate`]-mode (in which case its panic is re-raised unless this has been done already).
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and waiting for its jobs in two batches:
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// let counter = Arc::new(AtomicUsize::new(0));
+    ///
+    /// for batch in 1..=2 {
+    ///     for _ in 0..4 {
+    ///         let counter = Arc::clone(&counter);
+    ///         pool.execute(move || {
+    ///             counter.fetch_add(1, Ordering::SeqCst);
+    ///         });
+    ///     }
+    ///     pool.join();
+    ///     assert_eq!(batch * 4, counter.load(Ordering::SeqCst));
+    /// }
+    /// ```
+    pub fn join(&self) {
+        let (joiner_s, joiner_r) = bounded(1);
+
+        self.send(Message::Join(joiner_s));
+
+        joiner_r
+            .recv()
+            .expect("Joining failed. Pool is unreachable.");
+
+        self.supervisor.inlet.propagate();
+    }
+
+    /// Tells how many worker-threads `self` currently employs.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and checking its size:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(3, poolio::PanicSwitch::Kill).unwrap();
+    /// assert_eq!(3, pool.size());
+    /// ```
+    pub fn size(&self) -> usize {
+        self.supervisor
+            .inlet
+            .noticeboard
+            .size
+            .load(AtomicOrdering::SeqCst)
+    }
+
+    /// Takes a snapshot of the figures of `self`.
+    ///
+    /// The figures are published by the supervisor whenever it has dealt with what happened in the pool, so reading them is as cheap as a few atomic loads.
+    /// Note that they are not taken at the very same instant and may thus be slightly inconsistent with each other.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool and checking whether it is saturated:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// pool.execute(|| println!("done"));
+    /// pool.join();
+    ///
+    /// let stats = pool.stats();
+    /// assert_eq!(2, stats.configured_workers);
+    /// assert_eq!(1, stats.completed_jobs);
+    /// assert!(stats.idle_workers > 0 || stats.queued_jobs == 0);
+    /// ```
+    pub fn stats(&self) -> PoolStats {
+        self.supervisor.inlet.stats()
+    }
+
+    /// Takes a snapshot of the latency metrics of `self`, that is, histograms of how long jobs waited in the queue and how long they ran.
+    ///
+    /// It returns [`None`] if `self` does not record latencies (see [`ThreadPoolBuilder::latency_metrics`]).
+    /// Delayed and recurring jobs are considered waiting from when they are due on.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool which records latencies and checking how long its jobs waited for the 95th percentile:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPoolBuilder::new(2)
+    ///     .latency_metrics(true)
+    ///     .build()
+    ///     .unwrap();
+    /// pool.execute(|| println!("done"));
+    /// pool.join();
+    ///
+    /// let latencies = pool.latencies().unwrap();
+    /// assert!(latencies.queue_wait.p95().is_some());
+    /// ```
+    pub fn latencies(&self) -> Option<Latencies> {
+        self.supervisor.inlet.latencies()
+    }
+
+    /// Forgets the latencies recorded by `self` so far (see [`ThreadPool::latencies`]).
+    pub fn reset_latencies(&self) {
+        if let Some(times) = &self.supervisor.inlet.times {
+            times.reset();
+        }
+    }
+
+    /// Changes the number of worker-threads of `self`.
+    /// - `size` is the (non-zero) number of worker-threads the pool is supposed to have.
+    ///
+    /// For an elastic pool (see [`ThreadPoolBuilder::max_size`]), `size` is the new minimal number of worker-threads and the maximal number is raised to `size` if necessary.
+    ///
+    /// Growing the pool takes effect immediately.
+    /// Shrinking the pool takes effect as soon as enough workers are idle since running jobs are never interrupted.
+    /// This method returns the size of the pool once the change has taken effect.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if
+    /// 1. 0 was passed as `size` (since a pool without worker-threads does not make sense).
+    /// 2. a thread could not be spawned (in which case the pool keeps the workers it could spawn).
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the pool is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool with two worker-threads and adjusting its size:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+    /// assert_eq!(8, pool.resize(8).unwrap());
+    /// assert_eq!(1, pool.resize(1).unwrap());
+    /// ```
+    pub fn resize(&self, size: usize) -> Result<usize, Error> {
+        let (resizer_s, resizer_r) = bounded(1);
+
+        self.send(Message::Resize(size, resizer_s));
+
+        resizer_r
+            .recv()
+            .expect("Resizing failed. Pool is unreachable.")
+    }
+
+    /// Shuts down `self` according to `mode` and reports what happened to the jobs.
+    /// - `mode` specifies what happens to the jobs which have not been started yet.
+    ///
+    /// Note that running jobs are finished in any case and that a panicked job in [`PanicSwitch::Kill`]-mode still kills the process.
+    ///
+    /// # Panics
+    ///
+    /// A panic occurs if
+    /// 1. the pool is unreachable.
+    /// 2. joining the threads panics.
+    /// 3. a job has panicked in [`PanicSwitch::Propagate`]-mode (in which case its panic is re-raised unless this has been done already).
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool with a long-running job and getting back the jobs queued behind it:
+    ///
+    /// ```
+    /// use std::sync::{Arc, Barrier};
+    ///
+    /// let pool = poolio::ThreadPool::new(1, poolio::PanicSwitch::Kill).unwrap();
+    /// let handle = pool.handle();
+    /// let barrier = Arc::new(Barrier::new(2));
+    /// let gate = Arc::clone(&barrier);
+    /// pool.execute(move || {
+    ///     // once it has started, the job waits for the other one to be queued
+    ///     gate.wait();
+    ///     gate.wait();
+    ///     // and keeps running until the shutdown has taken that one from the queue
+    ///     while handle.stats().queued_jobs > 0 {
+    ///         std::thread::yield_now();
+    ///     }
+    /// });
+    /// barrier.wait();
+    /// pool.execute(|| println!("late"));
+    /// barrier.wait();
+    ///
+    /// let report = pool.shutdown(poolio::ShutdownMode::Immediate);
+    /// assert_eq!(1, report.completed);
+    /// assert_eq!(1, report.discarded.len());
+    /// ```
+    pub fn shutdown(mut self, mode: ShutdownMode) -> ShutdownReport {
+        let report = self.terminate(mode);
+        self.supervisor.inlet.propagate();
+        report
+    }
+
+    /// Tries to shut down `self` according to `mode`.
+    ///
+    /// # Panics
+    ///
+    /// A panic occurs if
+    /// 1. the pool is unreachable.
+    /// 2. joining the threads panics.
+    fn terminate(&mut self, mode: ShutdownMode) -> ShutdownReport {
+        let (report_s, report_r) = bounded(1);
+
+        self.send(Message::Shutdown(mode, report_s));
+
+        thread::join(&mut self.supervisor.thread);
+
+        report_r
+            .recv()
+            .expect("Shutting down failed. Pool is unreachable.")
+    }
+
+    /// Wraps sending a [`Message`] to the pool.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the receiver has already been deallocated.
+    fn send(&self, msg: Message) {
+        self.supervisor.inlet.send(msg);
+    }
+}
+
+impl Drop for ThreadPool {
+    /// Tries to shut down `self` gracefully.
+    ///
+    /// In particular, one has to assume that all remaining jobs will be finished (modulo panics in [`PanicSwitch::Kill`]-mode).
+    ///
+    /// # Panics
+    ///
+    /// A panic occurs if
+    /// 1. the pool is unreachable
+    /// 2. joining the threads panics.
+    /// 3. a job has panicked in [`PanicSwitch::Propagate`]-mode and its panic has not been re-raised yet (unless the thread is panicking already).
+    ///
+    /// Remember that a panic while dropping aborts the whole process.
+    fn drop(&mut self) {
+        // the pool might have been shut down explicitly already
+        if self.supervisor.thread.is_some() {
+            self.terminate(ShutdownMode::Graceful);
+
+            // a second panic would abort the process
+            if !std::thread::panicking() {
+                self.supervisor.inlet.propagate();
+            }
+        }
+    }
+}
+
+/// [`Scope`]s are where jobs borrowing non-`'static` data run (see [`ThreadPool::scope`]).
+///
+/// As a scope borrows its pool, the pool cannot be shut down while jobs borrowing data may be handed out in its [`ShutdownReport`]:
+///
+/// ```compile_fail
+/// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+/// pool.scope(|s| {
+///     s.execute(|| ());
+///     pool.shutdown(poolio::ShutdownMode::Immediate);
+/// });
+/// ```
+pub struct Scope<'scope, 'env: 'scope> {
+    /// pool running the jobs
+    pool: &'scope ThreadPool,
+    /// place to report that a job is done
+    outcomes_s: Sender<Outcome>,
+    /// number of jobs run in the scope
+    jobs: AtomicUsize,
+    /// invariance over `'scope`
+    scope: PhantomData<&'scope mut &'scope ()>,
+    /// invariance over `'env`
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, 'env> Scope<'scope, 'env> {
+    /// Runs a job in the pool of `self`.
+    /// - `f` is the job to be run and has to be provided as a certain closure which may borrow data living as long as `self`.
+    ///
+    /// Note that if `f` panics, the behavior is according to the setting of the [`PanicSwitch`] of the pool.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool is unreachable.
+    /// 2. the pool does not accept jobs anymore (saying "Pool has been shut down."), e.g. after a job has panicked in [`PanicSwitch::Propagate`]-mode.
+    /// 3. the job queue is full and the pool is configured to reject jobs in that case (saying "Job queue is full.").
+    pub fn execute<F>(&'scope self, f: F)
+    where
+        F: FnOnce() + Send + 'scope,
+    {
+        self.jobs.fetch_add(1, AtomicOrdering::SeqCst);
+
+        let scoped_job = ScopedJob {
+            f: Some(Box::new(f)),
+            report: Some(Report {
+                outcomes_s: self.outcomes_s.clone(),
+                outcome: Outcome::Panicked(None),
+            }),
+        };
+
+        let job: Box<dyn FnOnce() + Send + 'scope> = Box::new(move || scoped_job.run());
+
+        // SAFETY: The job is sound to be run or dropped as long as the data it borrows lives.
+        // 1. [`ThreadPool::scope`] does not return before every job run in the scope has sent its report.
+        // 2. The report is only sent once `f` has been run or dropped as enforced by the `Drop` implementation of [`ScopedJob`].
+        //    Whichever way the job leaves the pool (run by a worker or the caller, panicked, evicted, cleared out or discarded), it is dropped at last.
+        // 3. The job is never handed out of the pool as a [`Job`] as this only happens on shutdown which cannot take place while the scope borrows the pool.
+        let job: Box<dyn FnOnce() + Send + 'static> = unsafe {
+            std::mem::transmute::<
+                Box<dyn FnOnce() + Send + 'scope>,
+                Box<dyn FnOnce() + Send + 'static>,
+            >(job)
+        };
+
+        self.pool.execute(AssertUnwindSafe(job));
+    }
+}
+
+/// [`ScopedJob`]s are jobs run in a [`Scope`] together with their [`Report`].
+///
+/// The job is always gone before its report is sent as the report allows the scope to end the borrows of the job.
+struct ScopedJob<'scope> {
+    /// the job (until it is run)
+    f: Option<Box<dyn FnOnce() + Send + 'scope>>,
+    /// the report sent after the job is done (until it is handed over along with a panic)
+    report: Option<Report>,
+}
+
+impl ScopedJob<'_> {
+    /// Runs the job and reports how it went.
+    fn run(mut self) {
+        let f = self.f.take().expect("Scoped job has been run already.");
+
+        match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(()) => {
+                if let Some(report) = &mut self.report {
+                    report.outcome = Outcome::Finished;
+                }
+            }
+            Err(payload) => {
+                // the supervisor gets to see the original payload before it is handed to the scope
+                let mut report = self.report.take();
+                let handoff: Handoff = Box::new(move |payload| {
+                    if let Some(report) = &mut report {
+                        report.outcome = Outcome::Panicked(Some(payload));
+                    }
+                });
+                std::panic::resume_unwind(Box::new(Panicked { payload, handoff }));
+            }
+        }
+    }
+}
+
+impl Drop for ScopedJob<'_> {
+    fn drop(&mut self) {
+        // the job has to be gone before the report allows the scope to end its borrows
+        drop(self.f.take());
+        drop(self.report.take());
+    }
+}
+
+/// [`Outcome`] is how a job run in a [`Scope`] went.
+enum Outcome {
+    /// The job has been finished.
+    Finished,
+    /// The job has panicked (with the given payload unless the pool keeps it) or has been dropped without being run.
+    Panicked(Option<Box<dyn Any + Send>>),
+}
+
+/// [`Report`]s are sent to a [`Scope`] when they are dropped.
+struct Report {
+    /// place to send the report to
+    outcomes_s: Sender<Outcome>,
+    /// what is reported
+    outcome: Outcome,
+}
+
+impl Drop for Report {
+    fn drop(&mut self) {
+        let outcome = std::mem::replace(&mut self.outcome, Outcome::Panicked(None));
+        // the scope waits for the report so it cannot have gone
+        let _ = self.outcomes_s.send(outcome);
+    }
+}
+
+impl fmt::Debug for Scope<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+/// [`ThreadPoolBuilder`]s set up [`ThreadPool`]s with a custom configuration.
+///
+/// # Examples
+///
+/// Setting up a pool whose worker-threads are called 'myservice-worker-0' and 'myservice-worker-1':
+///
+/// ```
+/// let pool = poolio::ThreadPoolBuilder::new(2)
+///     .name_prefix("myservice-worker")
+///     .stack_size(4 * 1024 * 1024)
+///     .supervisor_name("myservice-supervisor")
+///     .panic_switch(poolio::PanicSwitch::Respawn)
+///     .build()
+///     .unwrap();
+///
+/// let name = pool.spawn(|| std::thread::current().name().map(String::from));
+/// assert!(name.join().unwrap().unwrap().starts_with("myservice-worker-"));
+/// ```
+pub struct ThreadPoolBuilder {
+    /// (minimal) number of worker-threads
+    size: usize,
+    /// maximal number of worker-threads
+    max_size: Option<usize>,
+    /// how long surplus worker-threads may be idle
+    keep_alive: Duration,
+    /// maximal number of queued jobs
+    queue_capacity: Option<usize>,
+    /// what happens to jobs which do not fit into the queue
+    policy: RejectionPolicy,
+    /// how often a job may be passed over by jobs of higher priority
+    aging: usize,
+    /// what happens to delayed jobs which are not due on shutdown
+    timer_shutdown: TimerShutdown,
+    /// whether the latencies of the jobs are recorded
+    latency_metrics: bool,
+    /// what to do about panicked jobs
+    panic_policy: Box<dyn PanicPolicy>,
+    /// how many respawns are allowed within which time and what to do beyond that
+    restart_intensity: Option<(usize, Duration, PanicDecision)>,
+    /// prefix of the worker-threads' names
+    name_prefix: String,
+    /// name of the supervisor-thread
+    supervisor_name: String,
+    /// stack size of the worker-threads in bytes
+    stack_size: Option<usize>,
+    /// what each worker-thread does first
+    on_thread_start: Option<Hook>,
+    /// what each worker-thread does last
+    on_thread_stop: Option<Hook>,
+    /// who is told about what happens in the pool
+    observers: Vec<Arc<dyn PoolObserver>>,
+}
+
+impl ThreadPoolBuilder {
+    /// Starts configuring a new pool.
+    /// - `size` is the (non-zero) number of worker-threads in the pool.
+    ///
+    /// By default, the pool is in [`PanicSwitch::Kill`]-mode, the worker-threads are called 'poolio-worker-' followed by their [`StaffNumber`], the supervisor-thread is called 'poolio-supervisor' and the stack size is the one of [`std::thread`].
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            max_size: None,
+            keep_alive: Duration::from_secs(60),
+            queue_capacity: None,
+            policy: RejectionPolicy::Block,
+            aging: 16,
+            timer_shutdown: TimerShutdown::Discard,
+            latency_metrics: false,
+            panic_policy: Box::new(PanicSwitch::Kill),
+            restart_intensity: None,
+            name_prefix: String::from("poolio-worker"),
+            supervisor_name: String::from("poolio-supervisor"),
+            stack_size: None,
+            on_thread_start: None,
+            on_thread_stop: None,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Sets the panic switch.
+    /// - `mode` is the setting of the panic switch.
+    pub fn panic_switch(mut self, mode: PanicSwitch) -> Self {
+        self.panic_policy = Box::new(mode);
+        self
+    }
+
+    /// Sets a policy deciding what to do about panicked jobs in place of the panic switch.
+    /// - `policy` is the policy.
+    pub fn panic_policy<P: PanicPolicy>(mut self, policy: P) -> Self {
+        self.panic_policy = Box::new(policy);
+        self
+    }
+
+    /// Limits how often the pool respawns worker-threads in the style of OTP supervisors.
+    /// - `max_restarts` is the number of respawns allowed within `window`.
+    /// - `window` is the time span the respawns are counted in.
+    /// - `fallback` is what the pool does about panicked jobs once the limit has been exceeded.
+    ///
+    /// Once a panicked job would make the pool respawn more than `max_restarts` worker-threads within `window`, the pool escalates:
+    /// from then on every panicked job is dealt with according to `fallback` instead of the panic switch or policy.
+    /// The escalation is reported by [`PoolStats::escalated`].
+    /// Note that [`PanicDecision::Retire`] as `fallback` lets the pool degrade by giving up the polluted threads down to a single one:
+    /// once the last worker-thread has a panicked job, the pool is closed instead (see [`PanicDecision::Retire`]).
+    /// `fallback` must not be [`PanicDecision::Respawn`] (since it respawns worker-threads) which is checked by [`ThreadPoolBuilder::build`].
+    ///
+    /// By default, the pool respawns worker-threads without limit.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool which gives up worker-threads polluted by a panicking job once they have been respawned twice within a minute:
+    ///
+    /// ```
+    /// use poolio::PanicDecision;
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPoolBuilder::new(2)
+    ///     .panic_switch(poolio::PanicSwitch::Respawn)
+    ///     .restart_intensity(2, Duration::from_secs(60), PanicDecision::Retire)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// for _ in 0..3 {
+    ///     pool.execute(|| panic!("Oh no!"));
+    /// }
+    /// pool.join();
+    ///
+    /// let stats = pool.stats();
+    /// assert_eq!(2, stats.respawns);
+    /// assert!(stats.escalated);
+    /// assert_eq!(1, pool.size());
+    /// ```
+    pub fn restart_intensity(
+        mut self,
+        max_restarts: usize,
+        window: Duration,
+        fallback: PanicDecision,
+    ) -> Self {
+        self.restart_intensity = Some((max_restarts, window, fallback));
+        self
+    }
+
+    /// Makes the pool elastic, that is, lets it employ additional worker-threads when there is more to do than idle workers.
+    /// - `max_size` is the maximal number of worker-threads in the pool.
+    ///
+    /// The number of worker-threads the pool has been configured with is the minimal number of worker-threads then.
+    /// If `max_size` is not greater than that, the pool is not elastic.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool with one to four worker-threads whose surplus threads go away after being idle for a second:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPoolBuilder::new(1)
+    ///     .max_size(4)
+    ///     .keep_alive(Duration::from_secs(1))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// for _ in 0..4 {
+    ///     pool.execute(|| std::thread::sleep(Duration::from_millis(100)));
+    /// }
+    /// pool.join();
+    /// assert!(pool.size() > 1);
+    /// ```
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets how long surplus worker-threads of an elastic pool may be idle before they are dismissed.
+    /// - `keep_alive` is the time a surplus worker-thread may be idle.
+    ///
+    /// By default, surplus worker-threads are kept alive for a minute.
+    pub fn keep_alive(mut self, keep_alive: Duration) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Bounds the job queue of the pool.
+    /// - `capacity` is the (non-zero) maximal number of jobs waiting for a worker (of all [`Priority`]s together).
+    ///
+    /// By default, the job queue is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool which runs jobs on the ordering thread if more than 16 jobs are waiting:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPoolBuilder::new(2)
+    ///     .queue_capacity(16)
+    ///     .rejection_policy(poolio::RejectionPolicy::CallerRuns)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// for _ in 0..64 {
+    ///     pool.execute(|| println!("done"));
+    /// }
+    /// assert!(pool.queued_len() <= 16);
+    /// ```
+    pub fn queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets what happens to jobs which are ordered when the job queue is full.
+    /// - `policy` is the rejection policy.
+    ///
+    /// By default, the pool is configured with [`RejectionPolicy::Block`].
+    /// Note that the policy has no effect if the job queue is unbounded.
+    pub fn rejection_policy(mut self, policy: RejectionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the starvation guard of the job queue.
+    /// - `aging` is how often pending jobs of some [`Priority`] may be passed over by jobs of higher priority before one of them is run anyway.
+    ///
+    /// By default, pending jobs may be passed over 16 times.
+    /// Note that 0 disables the starvation guard, that is, jobs of lower priority only run when no job of higher priority is pending.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool where pending low-priority jobs are run after at most four high-priority jobs:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPoolBuilder::new(2).aging(4).build().unwrap();
+    /// ```
+    pub fn aging(mut self, aging: usize) -> Self {
+        self.aging = aging;
+        self
+    }
+
+    /// Sets what happens to delayed jobs which are not due yet when the pool is shut down.
+    /// - `timer_shutdown` is the setting for those jobs.
+    ///
+    /// By default, the pool is configured with [`TimerShutdown::Discard`].
+    /// Note that the setting does not keep a [`ShutdownMode::Deadline`] or [`ShutdownMode::Immediate`] from discarding the jobs.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool which runs delayed jobs before it shuts down:
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let pool = poolio::ThreadPoolBuilder::new(1)
+    ///     .timer_shutdown(poolio::TimerShutdown::Wait)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// pool.execute_after(Duration::from_millis(10), || println!("done"));
+    /// let report = pool.shutdown(poolio::ShutdownMode::Graceful);
+    /// assert_eq!(1, report.completed);
+    /// ```
+    pub fn timer_shutdown(mut self, timer_shutdown: TimerShutdown) -> Self {
+        self.timer_shutdown = timer_shutdown;
+        self
+    }
+
+    /// Sets whether the pool records how long its jobs wait in the queue and how long they run (see [`ThreadPool::latencies`]).
+    /// - `enabled` tells whether the latencies are recorded.
+    ///
+    /// By default, the latencies are not recorded as this costs taking two timestamps per job.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool which records latencies:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPoolBuilder::new(2)
+    ///     .latency_metrics(true)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(pool.latencies().is_some());
+    /// ```
+    pub fn latency_metrics(mut self, enabled: bool) -> Self {
+        self.latency_metrics = enabled;
+        self
+    }
+
+    /// Sets the prefix of the worker-threads' names.
+    /// - `prefix` is what precedes the '-' and the [`StaffNumber`] in the name of a worker-thread.
+    pub fn name_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.name_prefix = prefix.into();
+        self
+    }
+
+    /// Sets the name of the supervisor-thread.
+    /// - `name` is the name of the supervisor-thread.
+    pub fn supervisor_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.supervisor_name = name.into();
+        self
+    }
+
+    /// Sets the stack size of the worker-threads.
+    /// - `size` is the stack size in bytes.
+    pub fn stack_size(mut self, size: usize) -> Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    /// Sets what each worker-thread does before it takes its first job.
+    /// - `f` is the hook to be run and gets passed the [`StaffNumber`] of the worker.
+    ///
+    /// The hook is also run by the worker-threads which are respawned in [`PanicSwitch::Respawn`]-mode.
+    /// Note that a panic in the hook is not treated like a panicked job but like a worker-thread which could not be spawned:
+    /// the worker is lost and the pool shrinks by one worker-thread without consulting the panic switch or policy.
+    /// Once the pool has lost all its worker-threads this way, it stops accepting jobs and discards the ones which have not been started.
+    ///
+    /// # Examples
+    ///
+    /// Setting up a pool whose worker-threads know their staff number:
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// thread_local!(static ID: Cell<Option<poolio::StaffNumber>> = Cell::new(None));
+    ///
+    /// let pool = poolio::ThreadPoolBuilder::new(1)
+    ///     .on_thread_start(|id| ID.with(|cell| cell.set(Some(id))))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let id = pool.spawn(|| ID.with(|cell| cell.get()));
+    /// assert_eq!(Some(0), id.join().unwrap());
+    /// ```
+    pub fn on_thread_start<F>(mut self, f: F) -> Self
+    where
+        F: Fn(StaffNumber) + Send + Sync + 'static,
+    {
+        self.on_thread_start = Some(Arc::new(f));
+        self
+    }
+
+    /// Sets what each worker-thread does before it ends.
+    /// - `f` is the hook to be run and gets passed the [`StaffNumber`] of the worker.
+    ///
+    /// The hook is run when the worker is terminated as well as after the worker has reported a panicked job.
+    /// Note that a panic in the hook is ignored.
+    pub fn on_thread_stop<F>(mut self, f: F) -> Self
+    where
+        F: Fn(StaffNumber) + Send + Sync + 'static,
+    {
+        self.on_thread_stop = Some(Arc::new(f));
+        self
+    }
+
+    /// Registers an observer which is told about what happens in the pool.
+    /// - `observer` is the observer.
+    ///
+    /// Several observers may be registered which are told in the order of registration.
+    pub fn observer<O: PoolObserver>(mut self, observer: O) -> Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Sets up the configured pool.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if
+    /// 1. the configured size is 0 (since a pool without worker-threads does not make sense).
+    /// 2. the configured fallback of the restart intensity would respawn worker-threads.
+    /// 3. the configured queue capacity is 0.
+    /// 4. a thread could not be spawned.
+    pub fn build(self) -> Result<ThreadPool, Error> {
+        if self.size == 0 {
+            return Err(Error::ZeroWorkers);
+        };
+
+        if let Some((_, _, PanicDecision::Respawn)) = self.restart_intensity {
+            return Err(Error::InvalidFallback);
+        }
+
+        if self.queue_capacity == Some(0) {
+            return Err(Error::ZeroCapacity);
+        }
+
+        let pool = ThreadPool {
+            supervisor: Supervisor::new(self)?,
+        };
+        Ok(pool)
+    }
+}
+
+/// [`JobHandle`]s are the way to get the results of the jobs run by [`ThreadPool::spawn`].
+pub struct JobHandle<T> {
+    /// place to get the result from
+    result_r: Receiver<std::thread::Result<T>>,
+}
+
+impl<T> JobHandle<T> {
+    /// Waits for the job associated with `self` to finish.
+    ///
+    /// # Errors
+    ///
+    /// An error carrying the panic-payload is returned if the job panicked.
+    /// If the job was dropped without having been run, the payload is a [`&str`] saying so.
+    ///
+    /// # Examples
+    ///
+    /// Getting hold of the payload of a panicking job:
+    ///
+    /// ```
+    /// let pool = poolio::ThreadPool::new(1, poolio::PanicSwitch::Respawn).unwrap();
+    /// let handle = pool.spawn(|| -> usize { panic!("Oh no!") });
+    /// let payload = handle.join().unwrap_err();
+    /// assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+    /// ```
+    pub fn join(self) -> std::thread::Result<T> {
+        self.result_r.recv().unwrap_or_else(|_| {
+            let payload: Box<dyn Any + Send> = Box::new("Job has been dropped without being run.");
+            Err(payload)
+        })
+    }
+}
+
+impl<T> fmt::Debug for JobHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("JobHandle").finish_non_exhaustive()
+    }
+}
+
+/// [`Timer`]s are the way to cancel the jobs run by [`ThreadPool::execute_after`] and [`ThreadPool::execute_at`] before they are due
+/// as well as to stop the jobs run by [`ThreadPool::execute_every`] and [`ThreadPool::execute_recurring`].
+///
+/// Note that dropping a timer does not cancel its job.
+pub struct Timer {
+    /// id of the delayed job
+    id: u64,
+    /// what has become of the delayed job
+    state: Arc<AtomicU8>,
+    /// place to put the cancellation
+    orders_s: Sender<Message>,
+}
+
+impl Timer {
+    /// Cancels the job associated with `self` if it is not due yet or stops its recurrence.
+    ///
+    /// It returns whether the job has been cancelled, that is, `false` if the job has already been queued to run for the last time (or has been discarded on shutdown).
+    /// Note that a run of a recurring job which is in progress is finished anyway.
+    pub fn cancel(&self) -> bool {
+        let cancelled = self
+            .state
+            .compare_exchange(
+                Delayed::PENDING,
+                Delayed::CANCELLED,
+                AtomicOrdering::SeqCst,
+                AtomicOrdering::SeqCst,
+            )
+            .is_ok();
+
+        if cancelled {
+            // the pool may have gone which is not a problem
+            let _ = self.orders_s.send(Message::Cancel(self.id));
+        }
+
+        cancelled
+    }
+
+    /// Tells whether the job associated with `self` is still waiting to be due (or to recur).
+    pub fn is_pending(&self) -> bool {
+        self.state.load(AtomicOrdering::SeqCst) == Delayed::PENDING
+    }
+}
+
+impl fmt::Debug for Timer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Timer")
+            .field("pending", &self.is_pending())
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`CancellationToken`]s tell jobs whether they are supposed to stop (see [`ThreadPool::execute_cancellable`]).
+///
+/// Clones share the same cancellation.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    /// whether the token has been cancelled
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Sets up a token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels `self` (and all its clones).
+    pub fn cancel(&self) {
+        self.cancelled.store(true, AtomicOrdering::SeqCst);
+    }
+
+    /// Tells whether `self` has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(AtomicOrdering::SeqCst)
+    }
+}
+
+/// [`JobStatus`] is what has become of a job run by [`ThreadPool::execute_cancellable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobStatus {
+    /// The job is waiting for a worker.
+    Queued = 0,
+    /// The job is running.
+    Running = 1,
+    /// The job has finished without being cancelled.
+    Completed = 2,
+    /// The job has panicked.
+    Panicked = 3,
+    /// The job has been cancelled before it started (and will not run).
+    CancelledBeforeStart = 4,
+    /// The job has been cancelled while it was running.
+    CancelledWhileRunning = 5,
+}
+
+impl JobStatus {
+    /// Restores a status from its representation.
+    fn from_u8(status: u8) -> Self {
+        match status {
+            0 => Self::Queued,
+            1 => Self::Running,
+            2 => Self::Completed,
+            3 => Self::Panicked,
+            4 => Self::CancelledBeforeStart,
+            5 => Self::CancelledWhileRunning,
+            _ => unreachable!("Job statuses are only stored from their representation."),
+        }
+    }
+}
+
+/// Changes `state` from `from` to `to` and returns whether `state` was `from`.
+fn transition(state: &AtomicU8, from: JobStatus, to: JobStatus) -> bool {
+    state
+        .compare_exchange(
+            from as u8,
+            to as u8,
+            AtomicOrdering::SeqCst,
+            AtomicOrdering::SeqCst,
+        )
+        .is_ok()
+}
+
+/// [`CancelHandle`]s are the way to cancel the jobs run by [`ThreadPool::execute_cancellable`] and to learn what has become of them.
+///
+/// Note that dropping a handle does not cancel its job.
+pub struct CancelHandle {
+    /// what has become of the job
+    state: Arc<AtomicU8>,
+    /// the token handed to the job
+    token: CancellationToken,
+    /// place to take the job from if it has not started
+    slot_r: Receiver<Box<dyn Any + Send>>,
+    /// way to take the job out of the job queue if it has not started
+    withdrawal: Arc<Withdrawal>,
+    /// place to wait for the job to be done
+    done_r: Receiver<()>,
+}
+
+impl CancelHandle {
+    /// Cancels the job associated with `self`.
+    ///
+    /// If the job has not started yet, it is dropped without being run.
+    /// If it is running, its [`CancellationToken`] is cancelled.
+    /// It returns whether the job has been cancelled, that is, `false` if it is done already.
+    pub fn cancel(&self) -> bool {
+        if transition(
+            &self.state,
+            JobStatus::Queued,
+            JobStatus::CancelledBeforeStart,
+        ) {
+            drop(self.slot_r.try_recv());
+            self.withdrawal.withdraw();
+            return true;
+        }
+
+        self.token.cancel();
+        transition(
+            &self.state,
+            JobStatus::Running,
+            JobStatus::CancelledWhileRunning,
+        )
+    }
+
+    /// Tells what has become of the job associated with `self`.
+    pub fn status(&self) -> JobStatus {
+        JobStatus::from_u8(self.state.load(AtomicOrdering::SeqCst))
+    }
+
+    /// Waits for the job associated with `self` to be done and tells what has become of it.
+    ///
+    /// Note that it returns [`JobStatus::Queued`] if the job has been dropped without being run, e.g. on shutdown.
+    pub fn wait(&self) -> JobStatus {
+        if self.status() != JobStatus::CancelledBeforeStart {
+            // the job only disconnects when it is done or dropped
+            let _ = self.done_r.recv();
+        }
+        self.status()
+    }
+}
+
+impl fmt::Debug for CancelHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CancelHandle")
+            .field("status", &self.status())
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`JobContext`]s tell the jobs run by [`ThreadPool::execute_with_context`] about the circumstances they are run in.
+pub struct JobContext {
+    /// worker running the job
+    worker: Option<StaffNumber>,
+    /// id of the job
+    job: u64,
+    /// token telling whether the job has been cancelled
+    token: CancellationToken,
+    /// when the job is supposed to be done
+    deadline: Option<Instant>,
+    /// way to order follow-up jobs
+    pool: PoolHandle,
+}
+
+impl JobContext {
+    /// Tells the [`StaffNumber`] of the worker running the job.
+    ///
+    /// Note that it is [`None`] if the job is not run by a worker, e.g. due to [`RejectionPolicy::CallerRuns`].
+    pub fn worker_id(&self) -> Option<StaffNumber> {
+        self.worker
+    }
+
+    /// Tells the id of the job which is unique in the pool and increasing in the order the jobs have been ordered.
+    pub fn job_id(&self) -> u64 {
+        self.job
+    }
+
+    /// Tells whether the job has been cancelled (see [`CancelHandle::cancel`]).
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Hands out the [`CancellationToken`] of the job, e.g. to pass it on.
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+
+    /// Tells when the job is supposed to be done (see [`ThreadPool::execute_with_deadline`]).
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Tells whether the deadline of the job has passed.
+    pub fn is_overdue(&self) -> bool {
+        matches!(self.deadline, Some(deadline) if Instant::now() >= deadline)
+    }
+
+    /// Hands out a [`PoolHandle`] to order follow-up jobs in the pool running the job.
+    pub fn pool(&self) -> &PoolHandle {
+        &self.pool
+    }
+}
+
+impl fmt::Debug for JobContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("JobContext")
+            .field("worker", &self.worker)
+            .field("job", &self.job)
+            .field("cancelled", &self.is_cancelled())
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+/// [`PoolHandle`]s are the way to order jobs in a [`ThreadPool`] from elsewhere (see [`ThreadPool::handle`] and [`JobContext::pool`]).
+///
+/// Clones order jobs in the same pool.
+#[derive(Clone)]
+pub struct PoolHandle {
+    /// way into the pool
+    inlet: Inlet,
+}
+
+impl PoolHandle {
+    /// Runs a job in the pool just like [`ThreadPool::execute`].
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.execute_with_priority(Priority::Normal, f);
+    }
+
+    /// Runs a job in the pool with a certain priority just like [`ThreadPool::execute_with_priority`].
+    /// - `priority` is the rank of the job in the job queue.
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    pub fn execute_with_priority<F>(&self, priority: Priority, f: F)
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.inlet.execute(priority, Box::new(f));
+    }
+
+    /// Tries to run a job in the pool just like [`ThreadPool::try_execute`].
+    /// - `f` is the job to be run and has to be provided as a certain closure.
+    ///
+    /// # Errors
+    ///
+    /// An error handing back `f` is returned if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    pub fn try_execute<F>(&self, f: F) -> Result<(), ExecuteError<F>>
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.inlet.try_execute(Priority::Normal, f)
+    }
+
+    /// Runs a job in the pool which is given a [`JobContext`] just like [`ThreadPool::execute_with_context`].
+    /// - `f` is the job to be run and has to be provided as a certain closure taking a context.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    pub fn execute_with_context<F>(&self, f: F) -> CancelHandle
+    where
+        F: FnOnce(&JobContext) + UnwindSafe + Send + 'static,
+    {
+        self.inlet.execute_with_context(None, f)
+    }
+
+    /// Takes a snapshot of the figures of the pool just like [`ThreadPool::stats`].
+    pub fn stats(&self) -> PoolStats {
+        self.inlet.stats()
+    }
+
+    /// Takes a snapshot of the latency metrics of the pool just like [`ThreadPool::latencies`].
+    pub fn latencies(&self) -> Option<Latencies> {
+        self.inlet.latencies()
+    }
+}
+
+impl fmt::Debug for PoolHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PoolHandle").finish_non_exhaustive()
+    }
+}
+
+/// [`StaffNumber`]s identify workers.
+pub type StaffNumber = usize;
+
+/// Types the hooks run by the worker-threads.
+type Hook = Arc<dyn Fn(StaffNumber) + Send + Sync + 'static>;
+
+/// [`Status`] is what worker with [`StaffNumber`] is currently doing.
+enum Status {
+    /// worker `id` is idle.
+    Idle(StaffNumber),
+    /// worker `id` has a panicked job which panicked with the given payload (to be handed over if someone waits for it).
+    Panic(StaffNumber, Box<dyn Any + Send>, Option<Handoff>),
+    /// worker `id` could not get ready as its start hook panicked.
+    Unready(StaffNumber),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Idle(_) => write!(f, "[idle]"),
+            Self::Panic(_, _, _) => write!(f, "[panic]"),
+            Self::Unready(_) => write!(f, "[unready]"),
+        }
+    }
+}
+
+/// [`Supervisor`] abstracts the supervisors.
+struct Supervisor {
+    /// way to put orders and jobs
+    inlet: Inlet,
+    /// handle to join
+    thread: JoinHandle,
+}
+
+/// [`Inlet`] is the way into the pool.
+#[derive(Clone)]
+struct Inlet {
+    /// place to put orders
+    orders_s: Sender<Message>,
+    /// places to put jobs (one per [`Priority`])
+    jobs_s: Vec<Sender<Box<dyn Task>>>,
+    /// places to take jobs from to make room in the queue (only in [`RejectionPolicy::DropOldest`]-mode)
+    jobs_r: Option<Vec<Receiver<Box<dyn Task>>>>,
+    /// place to take up a slot of the queue (only if the queue is bounded)
+    slots_s: Option<Sender<()>>,
+    /// what happens to jobs which do not fit into the queue
+    policy: RejectionPolicy,
+    /// place where the supervisor-thread publishes information on the pool
+    noticeboard: Arc<Noticeboard>,
+    /// source of the ids of delayed jobs
+    timer_ids: Arc<AtomicU64>,
+    /// source of the ids of jobs run with a [`JobContext`]
+    job_ids: Arc<AtomicU64>,
+    /// place to record the latencies of the jobs (unless disabled)
+    times: Option<Arc<JobTimes>>,
+    /// who is told about accepted jobs
+    observers: Observers,
+}
+
+impl Supervisor {
+    /// Sets up a supervisor.
+    /// - `config` specifies how many workers are employed on which [`Contract`], what happens when workers report panicking jobs and how the supervisor-thread is called.
+    ///
+    /// In particular, it creates the workers and spawns a thread controlling them.
+    /// Moreover, it sets up a way to communicate to the thread.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a thread could not be spawned.
+    /// In that case, the workers which could be spawned are dismissed again.
+    fn new(config: ThreadPoolBuilder) -> Result<Self, Error> {
+        let ThreadPoolBuilder {
+            size,
+            max_size,
+            keep_alive,
+            queue_capacity,
+            policy,
+            aging,
+            timer_shutdown,
+            latency_metrics,
+            panic_policy,
+            restart_intensity,
+            name_prefix,
+            supervisor_name,
+            stack_size,
+            on_thread_start,
+            on_thread_stop,
+            observers,
+        } = config;
+
+        let observers = Observers(observers.into());
+        let contract = Arc::new(Contract {
+            name_prefix,
+            stack_size,
+            on_thread_start,
+            on_thread_stop,
+            observers: observers.clone(),
+        });
+
+        // this channel is used by the pool to contact the supervisor
+        let (orders_s, orders_r) = channel();
+        // these channels are used by the pool to queue jobs of each priority for the supervisor
+        let (jobs_s, jobs_r): (Vec<_>, Vec<_>) = (0..Priority::LEVELS)
+            .map(|_| channel::<Box<dyn Task>>())
+            .unzip();
+        // this channel bounds the number of jobs in all queues together
+        let (slots_s, slots_r) = queue_capacity.map(bounded::<()>).unzip();
+        let evictions_r = match policy {
+            RejectionPolicy::DropOldest => Some(jobs_r.clone()),
+            _ => None,
+        };
+        // this channel is used by the workers to contact the supervisor
+        let (statuses_s, statuses_r) = channel();
+
+        // construct `size` worker-threads
+        let mut staff = Staff::new(statuses_s, contract);
+        for _ in 0..size {
+            if let Err(e) = staff.hire() {
+                staff.dismiss_all();
+                return Err(Error::SpawnFailed(e));
+            }
+        }
+
+        // this is where the supervisor publishes information on the pool
+        let noticeboard = Arc::new(Noticeboard {
+            min_size: AtomicUsize::new(size),
+            size: AtomicUsize::new(size),
+            ..Noticeboard::default()
+        });
+
+        let management = Management {
+            staff,
+            backlog: Backlog::new(jobs_r, slots_r, aging, Arc::clone(&noticeboard)),
+            timers: Timers::new(),
+            orders_r,
+            statuses_r,
+            published: Arc::clone(&noticeboard),
+            min_size: size,
+            max_size: max_size.unwrap_or(size).max(size),
+            keep_alive,
+            timer_shutdown,
+            panic_policy,
+            restart_intensity,
+            resizers: Vec::new(),
+            joiners: Vec::new(),
+            shutdown: None,
+            completed_jobs: 0,
+            panicked_jobs: 0,
+            respawns: 0,
+            discarded_jobs: Vec::new(),
+            closing: false,
+            aborting: false,
+            propagating: false,
+            restarts: VecDeque::new(),
+            escalated: false,
+        };
+
+        // the management is moved into the supervisor-thread but its staff has to be dismissed if the thread cannot be spawned
+        let (management_s, management_r) = bounded(1);
+        management_s.send(management).unwrap();
+        let recovery_r = management_r.clone();
+
+        let builder = std::thread::Builder::new().name(supervisor_name);
+
+        let spawned = thread::spawn(builder, move || {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("supervisor").entered();
+
+            let management: Management = management_r.recv().unwrap();
+            management.run();
+        });
+
+        match spawned {
+            Ok(thread) => Ok(Self {
+                inlet: Inlet {
+                    orders_s,
+                    jobs_s,
+                    jobs_r: evictions_r,
+                    slots_s,
+                    policy,
+                    noticeboard,
+                    timer_ids: Arc::new(AtomicU64::new(0)),
+                    job_ids: Arc::new(AtomicU64::new(0)),
+                    times: latency_metrics.then(|| Arc::new(JobTimes::default())),
+                    observers,
+                },
+                thread,
+            }),
+            Err(e) => {
+                if let Ok(mut management) = recovery_r.try_recv() {
+                    management.staff.dismiss_all();
+                }
+                Err(Error::SpawnFailed(e))
+            }
+        }
+    }
+}
+
+impl Inlet {
+    /// Re-raises the panic of the first panicked job unless this has been done already (in propagate-mode).
+    fn propagate(&self) {
+        let panic = self.noticeboard.panic.lock().unwrap().take();
+        if let Some(payload) = panic {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    /// Tells how many jobs are waiting for a worker, that is, those in the job queue and those the supervisor holds back.
+    ///
+    /// The stubs of cancelled jobs left in the queue are not counted.
+    fn queued_len(&self) -> usize {
+        (self.jobs_s.iter().map(Sender::len).sum::<usize>()
+            + self.noticeboard.held.load(AtomicOrdering::Relaxed))
+        .saturating_sub(self.noticeboard.withdrawn.load(AtomicOrdering::SeqCst))
+    }
+
+    /// Takes a snapshot of the figures published on the noticeboard.
+    fn stats(&self) -> PoolStats {
+        let noticeboard = &self.noticeboard;
+        let load = |figure: &AtomicUsize| figure.load(AtomicOrdering::Relaxed);
+
+        PoolStats {
+            configured_workers: load(&noticeboard.min_size),
+            live_workers: load(&noticeboard.size),
+            idle_workers: load(&noticeboard.idle),
+            queued_jobs: self.queued_len(),
+            running_jobs: load(&noticeboard.busy),
+            completed_jobs: load(&noticeboard.completed),
+            panicked_jobs: load(&noticeboard.panicked),
+            evicted_jobs: load(&noticeboard.evicted),
+            respawns: load(&noticeboard.respawns),
+            escalated: noticeboard.escalated.load(AtomicOrdering::Relaxed),
+        }
+    }
+
+    /// Takes a snapshot of the recorded latencies (if they are recorded).
+    fn latencies(&self) -> Option<Latencies> {
+        self.times.as_ref().map(|times| times.snapshot())
+    }
+
+    /// Hands `task` over to the supervisor according to the [`RejectionPolicy`] and panics if that fails.
+    /// - `priority` is the rank of `task` in the job queue.
+    /// - `task` is the job to be queued.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the policy is to reject jobs in that case.
+    fn execute(&self, priority: Priority, task: Box<dyn Task>) {
+        if let Err((_, error)) = self.submit(priority, task) {
+            panic!("Ordering [NewJob] failed. {}", error);
+        }
+    }
+
+    /// Hands `f` over to the supervisor according to the [`RejectionPolicy`] and hands it back if that fails.
+    /// - `priority` is the rank of `f` in the job queue.
+    /// - `f` is the job to be queued.
+    ///
+    /// # Errors
+    ///
+    /// An error handing back `f` is returned if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the policy is to reject jobs in that case.
+    fn try_execute<F>(&self, priority: Priority, f: F) -> Result<(), ExecuteError<F>>
+    where
+        F: FnOnce() + UnwindSafe + Send + 'static,
+    {
+        self.submit(priority, Box::new(f))
+            .map_err(|(task, error)| ExecuteError {
+                job: *task
+                    .into_any()
+                    .downcast::<F>()
+                    .expect("Rejected task has not been made of the submitted job."),
+                error,
+            })
+    }
+
+    /// Hands a job taking a [`JobContext`] over to the supervisor and hands out a [`CancelHandle`] to cancel it.
+    /// - `deadline` is when the job is supposed to be done.
+    /// - `f` is the job to be queued.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the pool is configured to reject jobs in that case.
+    fn execute_with_context<F>(&self, deadline: Option<Instant>, f: F) -> CancelHandle
+    where
+        F: FnOnce(&JobContext) + UnwindSafe + Send + 'static,
+    {
+        let job = self.job_ids.fetch_add(1, AtomicOrdering::Relaxed);
+        let state = Arc::new(AtomicU8::new(JobStatus::Queued as u8));
+        let token = CancellationToken::new();
+        // the job is kept here to be taken either by the worker or by a cancellation
+        let (slot_s, slot_r) = bounded::<Box<dyn Any + Send>>(1);
+        slot_s.send(Box::new(f)).unwrap();
+        // the job drops its sender when it is done which is what the handle waits for
+        let (done_s, done_r) = bounded::<()>(0);
+
+        let withdrawal = Arc::new(Withdrawal {
+            settled: AtomicBool::new(false),
+            noticeboard: Arc::clone(&self.noticeboard),
+        });
+
+        let handle = CancelHandle {
+            state: Arc::clone(&state),
+            token: token.clone(),
+            slot_r: slot_r.clone(),
+            withdrawal: Arc::clone(&withdrawal),
+            done_r,
+        };
+
+        let pool = PoolHandle {
+            inlet: self.clone(),
+        };
+        let task = WorkerAware(move |worker| {
+            let _done_s = done_s;
+
+            if !transition(&state, JobStatus::Queued, JobStatus::Running) {
+                // the job has been cancelled before it started
+                return;
+            }
+            let f = slot_r
+                .try_recv()
+                .ok()
+                .and_then(|f| f.downcast::<F>().ok())
+                .expect("Cancellable job has been taken by someone else.");
+
+            let ctx = JobContext {
+                worker,
+                job,
+                token,
+                deadline,
+                pool,
+            };
+            match std::panic::catch_unwind(AssertUnwindSafe(|| f(&ctx))) {
+                Ok(()) => {
+                    // a job cancelled while running stays cancelled
+                    transition(&state, JobStatus::Running, JobStatus::Completed);
+                }
+                Err(payload) => {
+                    state.store(JobStatus::Panicked as u8, AtomicOrdering::SeqCst);
+                    std::panic::resume_unwind(payload);
+                }
+            }
+        });
+        let task = Cancellable {
+            task: Box::new(task),
+            withdrawal,
+        };
+        self.execute(Priority::Normal, Box::new(task));
+
+        handle
+    }
+
+    /// Wraps sending a [`Message`] to the supervisor.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the receiver has already been deallocated.
+    fn send(&self, msg: Message) {
+        let panic_message = format!("Ordering {} failed. Pool is unreachable.", msg);
+
+        self.orders_s.send(msg).expect(&panic_message);
+    }
+
+    /// Hands `task` over to the supervisor according to the [`RejectionPolicy`].
+    /// - `priority` is the rank of `task` in the job queue.
+    /// - `task` is the job to be queued.
+    ///
+    /// # Errors
+    ///
+    /// An error handing back `task` is returned if
+    /// 1. the pool does not accept jobs anymore.
+    /// 2. the job queue is full and the policy is to reject jobs in that case.
+    fn submit(
+        &self,
+        priority: Priority,
+        task: Box<dyn Task>,
+    ) -> Result<(), (Box<dyn Task>, Error)> {
+        if self.noticeboard.closed.load(AtomicOrdering::SeqCst) {
+            return Err((task, Error::PoolShutDown));
+        }
+
+        // the task continues the trace it has been ordered in
+        #[cfg(feature = "tracing")]
+        let task = Traced::wrap(task, tracing::Span::current());
+
+        let task = Timed::wrap(task, Instant::now(), &self.times);
+
+        // a job takes up a slot of the queue (if it is bounded) before it is queued
+        let backoff = Backoff::new();
+        loop {
+            match self.slots_s.as_ref().map(|slots_s| slots_s.try_send(())) {
+                None | Some(Ok(())) => break,
+                Some(Err(TrySendError::Disconnected(()))) => {
+                    return Err((task, Error::PoolShutDown))
+                }
+                Some(Err(TrySendError::Full(()))) => (),
+            }
+
+            // the slot of a job withdrawn by a cancellation is taken over
+            if self.noticeboard.take_spare_slot() {
+                break;
+            }
+
+            match self.policy {
+                RejectionPolicy::Block => {
+                    // wait for the supervisor to take a job from the queue
+                    if let Some(slots_s) = &self.slots_s {
+                        if slots_s.send(()).is_err() {
+                            return Err((task, Error::PoolShutDown));
+                        }
+                    }
+                    break;
+                }
+                RejectionPolicy::Reject => return Err((task, Error::QueueFull)),
+                RejectionPolicy::CallerRuns => {
+                    self.observers.notify(|observer| observer.job_submitted());
+                    run_unsupervised(task);
+                    return Ok(());
+                }
+                RejectionPolicy::DropOldest => {
+                    // the slot of the oldest job of the lowest priority is taken over
+                    let evicted = self
+                        .jobs_r
+                        .iter()
+                        .flatten()
+                        .find_map(|jobs_r| jobs_r.try_recv().ok());
+                    match evicted {
+                        // the slot of a withdrawn job may have been taken over already
+                        Some(evicted) if evicted.withdrawn() => {
+                            if self.noticeboard.take_spare_slot() {
+                                break;
+                            }
+                            continue;
+                        }
+                        Some(_) => {
+                            self.noticeboard
+                                .evicted
+                                .fetch_add(1, AtomicOrdering::Relaxed);
+                            break;
+                        }
+                        None => (),
+                    }
+                    // the slots may be taken by jobs which are about to be queued or just have been taken from the queue
+                    backoff.snooze();
+                }
+            }
+        }
+
+        match self.jobs_s[priority as usize].send(task) {
+            Ok(()) => {
+                self.observers.notify(|observer| observer.job_submitted());
+                Ok(())
+            }
+            Err(SendError(task)) => Err((task, Error::PoolShutDown)),
+        }
+    }
+}
+
+/// [`Management`] is what the supervisor-thread keeps track of to control the pool.
+struct Management {
+    /// the workers
+    staff: Staff,
+    /// the job queue
+    backlog: Backlog,
+    /// the delayed jobs
+    timers: Timers,
+    /// place to take orders from
+    orders_r: Receiver<Message>,
+    /// place to take the statuses of the workers from
+    statuses_r: Receiver<Status>,
+    /// place to publish information on the pool
+    published: Arc<Noticeboard>,
+    /// number of workers the pool is supposed to have at least
+    min_size: usize,
+    /// number of workers the pool may have at most
+    max_size: usize,
+    /// how long surplus workers may be idle
+    keep_alive: Duration,
+    /// what happens to delayed jobs which are not due on shutdown
+    timer_shutdown: TimerShutdown,
+    /// what to do about panicked jobs
+    panic_policy: Box<dyn PanicPolicy>,
+    /// how many respawns are allowed within which time and what to do beyond that
+    restart_intensity: Option<(usize, Duration, PanicDecision)>,
+    /// who waits for the pool to have the number of workers it is supposed to have
+    resizers: Vec<Sender<Result<usize, Error>>>,
+    /// who waits for the pool to become idle
+    joiners: Vec<Sender<()>>,
+    /// who ordered the pool to shut down and from when on jobs are discarded
+    shutdown: Option<(Sender<ShutdownReport>, Option<Instant>)>,
+    /// number of jobs which have been finished
+    completed_jobs: usize,
+    /// number of jobs which have panicked
+    panicked_jobs: usize,
+    /// number of workers which have been respawned
+    respawns: usize,
+    /// jobs which have been discarded without being started
+    discarded_jobs: Vec<Job>,
+    /// whether a panicked job has made the pool shut down on its own
+    closing: bool,
+    /// whether a panicked job is about to kill the process
+    aborting: bool,
+    /// whether a panicked job is about to be re-raised to the owner of the pool
+    propagating: bool,
+    /// when the recent respawns took place (oldest first)
+    restarts: VecDeque<Instant>,
+    /// whether there have been too many respawns
+    escalated: bool,
+}
+
+impl Management {
+    /// Controls the pool until it is shut down and reports back afterwards.
+    fn run(mut self) {
+        // keepin' running to distribute jobs among idle workers
+        loop {
+            self.wait();
+
+            while let Ok(status) = self.statuses_r.try_recv() {
+                self.handle_status(status);
+            }
+            while let Ok(order) = self.orders_r.try_recv() {
+                self.handle_order(order);
+            }
+
+            self.adjust_staff();
+            self.clear_out();
+            self.release_timers();
+            self.enforce_deadline();
+            self.dispatch();
+            self.wind_down();
+            self.publish();
+
+            if self.finish_round() {
+                break;
+            }
+        }
+
+        self.terminate();
+    }
+
+    /// Tells whether the distribution of jobs has stopped, that is, after a panicked job or without workers.
+    fn halted(&self) -> bool {
+        self.aborting || self.propagating || self.max_size == 0
+    }
+
+    /// Waits until there is something to do.
+    fn wait(&mut self) {
+        let wake_up = self.next_wake_up();
+
+        let mut events = Select::new();
+        events.recv(&self.orders_r);
+        events.recv(&self.statuses_r);
+        // jobs are only of interest if there is someone to do them or someone could be hired to do them
+        // while they have to be cleared out as they come in once the distribution has stopped (unless the process is aborted anyway)
+        let hiring = self.staff.size() < self.max_size && self.staff.starting() == 0;
+        let listening = if self.halted() {
+            !self.aborting
+        } else {
+            !self.staff.idle.is_empty() || hiring
+        };
+        if listening {
+            for lane in &self.backlog.lanes {
+                events.recv(lane);
+            }
+        }
+
+        match wake_up {
+            Some(deadline) => {
+                let _ = events.ready_deadline(deadline);
+            }
+            None => {
+                events.ready();
+            }
+        }
+    }
+
+    /// Tells when there is something to do even if nothing happens in the meantime.
+    fn next_wake_up(&mut self) -> Option<Instant> {
+        // surplus workers have to be dismissed when they have been idle for too long
+        let dismissal = match self.staff.longest_idle() {
+            Some(since) if self.staff.size() > self.min_size => Some(since + self.keep_alive),
+            _ => None,
+        };
+        // delayed jobs have to be queued when they are due
+        let due = self.timers.next_due();
+        // the jobs which have not been started have to be discarded when the deadline of a shutdown has passed
+        let discard_from = match self.shutdown {
+            Some((_, discard_from)) if !(self.timers.is_empty() && self.backlog.is_empty()) => {
+                discard_from
+            }
+            _ => None,
+        };
+
+        [dismissal, due, discard_from].into_iter().flatten().min()
+    }
+
+    /// Deals with `status` reported by a worker.
+    fn handle_status(&mut self, status: Status) {
+        match status {
+            Status::Idle(id) => {
+                if self.staff.report_idle(id) {
+                    self.completed_jobs += 1;
+                }
+            }
+            Status::Unready(id) => {
+                // a worker which cannot get ready is lost like one which cannot be spawned and the pool shrinks
+                self.staff.release(id);
+                self.min_size = self.min_size.saturating_sub(1);
+                self.max_size = self.max_size.saturating_sub(1);
+
+                #[cfg(feature = "tracing")]
+                tracing::error!(worker = id, "Worker failed to start.");
+
+                if self.max_size == 0 {
+                    self.published.closed.store(true, AtomicOrdering::SeqCst);
+                }
+            }
+            Status::Panic(id, payload, handoff) => self.handle_panic(id, payload, handoff),
+        }
+    }
+
+    /// Deals with a panicked job of worker `id`.
+    /// - `payload` is what the job panicked with.
+    /// - `handoff` is the way to hand `payload` to whoever waits for the job's result (if someone does).
+    fn handle_panic(
+        &mut self,
+        id: StaffNumber,
+        payload: Box<dyn Any + Send>,
+        handoff: Option<Handoff>,
+    ) {
+        self.panicked_jobs += 1;
+        self.staff.release(id);
+
+        let decision = self.decide(id, &*payload);
+
+        // the first panic to be propagated keeps its original payload to be re-raised
+        // while otherwise the payload is handed to whoever waits for the job's result
+        let propagated = matches!(decision, PanicDecision::Propagate) && !self.propagating;
+        let payload = match (propagated, handoff) {
+            (true, Some(handoff)) => {
+                handoff(Box::new("Job panicked and the pool re-raises its panic."));
+                Some(payload)
+            }
+            (true, None) => Some(payload),
+            (false, Some(handoff)) => {
+                handoff(payload);
+                None
+            }
+            (false, None) => None,
+        };
+
+        match decision {
+            PanicDecision::Respawn => {
+                // a worker which cannot be respawned is lost
+                if self.staff.hire_as(id).is_ok() {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(worker = id, "Respawned worker.");
+
+                    self.respawns += 1;
+                    self.staff
+                        .contract
+                        .observers
+                        .notify(|observer| observer.worker_respawned(id));
+                }
+            }
+            PanicDecision::Close => {
+                // the remaining jobs are finished by the other workers (or by workers hired for them)
+                self.closing = true;
+                self.published.closed.store(true, AtomicOrdering::SeqCst);
+            }
+            PanicDecision::Retire => {
+                self.min_size = self.min_size.saturating_sub(1).max(1);
+                self.max_size -= 1;
+                // an elastic pool may be left with fewer workers than it is supposed to keep at least
+                while self.staff.size() < self.min_size {
+                    // a worker which cannot be hired is lost like one which cannot be respawned
+                    if self.staff.hire().is_err() {
+                        break;
+                    }
+                }
+            }
+            PanicDecision::Propagate => {
+                // only the first panic is re-raised
+                if let Some(payload) = payload {
+                    *self.published.panic.lock().unwrap() = Some(payload);
+                    self.propagating = true;
+                }
+                self.published.closed.store(true, AtomicOrdering::SeqCst);
+            }
+            PanicDecision::Abort => self.aborting = true,
+        }
+    }
+
+    /// Decides what to do about a panicked job of worker `id` taking the restart intensity into account.
+    /// - `payload` is what the job panicked with.
+    fn decide(&mut self, id: StaffNumber, payload: &(dyn Any + Send)) -> PanicDecision {
+        let decision = match self.restart_intensity {
+            // once escalated, the fallback decides
+            Some((_, _, fallback)) if self.escalated => fallback,
+            _ => {
+                let policy = &mut self.panic_policy;
+                std::panic::catch_unwind(AssertUnwindSafe(|| policy.on_panic(id, payload)))
+                    .unwrap_or(PanicDecision::Abort)
+            }
+        };
+
+        // a respawn beyond the restart intensity escalates to the fallback
+        let decision = match self.restart_intensity {
+            Some((max_restarts, window, fallback))
+                if matches!(decision, PanicDecision::Respawn) =>
+            {
+                let now = Instant::now();
+                while self.restarts.front().is_some_and(|at| *at + window <= now) {
+                    self.restarts.pop_front();
+                }
+
+                if self.restarts.len() < max_restarts {
+                    self.restarts.push_back(now);
+                    decision
+                } else {
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(
+                        max_restarts,
+                        ?window,
+                        ?fallback,
+                        "Exceeded restart intensity."
+                    );
+
+                    self.escalated = true;
+                    self.published
+                        .escalated
+                        .store(true, AtomicOrdering::Relaxed);
+                    fallback
+                }
+            }
+            _ => decision,
+        };
+
+        // the last worker is not retired to not leave the jobs without anyone to do them
+        match decision {
+            PanicDecision::Retire if self.max_size <= 1 => PanicDecision::Close,
+            decision => decision,
+        }
+    }
+
+    /// Carries out `order` from outside the pool.
+    fn handle_order(&mut self, order: Message) {
+        match order {
+            Message::Join(joiner) => self.joiners.push(joiner),
+            Message::Resize(size, resizer) => {
+                if size == 0 {
+                    // the resizer may have gone which is not a problem
+                    let _ = resizer.send(Err(Error::ZeroWorkers));
+                    return;
+                }
+                if self.closing {
+                    // the resizer may have gone which is not a problem
+                    let _ = resizer.send(Err(Error::PoolShutDown));
+                    return;
+                }
+
+                self.max_size = if self.max_size > self.min_size {
+                    self.max_size.max(size)
+                } else {
+                    size
+                };
+                self.min_size = size;
+                let mut failed = None;
+                while self.staff.size() < self.min_size {
+                    if let Err(e) = self.staff.hire() {
+                        self.min_size = self.staff.size();
+                        self.max_size = self.max_size.max(self.min_size);
+                        failed = Some(e);
+                        break;
+                    }
+                }
+                match failed {
+                    // the resizer may have gone which is not a problem
+                    Some(e) => {
+                        let _ = resizer.send(Err(Error::SpawnFailed(e)));
+                    }
+                    None => self.resizers.push(resizer),
+                }
+            }
+            Message::Schedule(at, delayed) => self.timers.schedule(at, delayed),
+            Message::Cancel(id) => self.timers.cancel(id),
+            Message::Shutdown(shutdown_mode, report_s) => {
+                let discard_from = match shutdown_mode {
+                    ShutdownMode::Graceful => None,
+                    ShutdownMode::Deadline(timeout) => Some(Instant::now() + timeout),
+                    ShutdownMode::Immediate => Some(Instant::now()),
+                };
+                self.shutdown = Some((report_s, discard_from));
+            }
+            Message::NewJob(_) | Message::Terminate => {
+                unreachable!("Jobs and terminations are not ordered from outside.")
+            }
+        }
+    }
+
+    /// Dismisses the workers the pool is not supposed to have (anymore) and tells the resizers once it has the right size.
+    fn adjust_staff(&mut self) {
+        // workers beyond the maximum are dismissed as soon as they are idle
+        while self.staff.size() > self.max_size {
+            match self.staff.idle.pop() {
+                Some(id) => self.staff.dismiss(id),
+                None => break,
+            }
+        }
+        if self.staff.size() <= self.max_size {
+            for resizer in self.resizers.drain(..) {
+                // the resizer may have gone which is not a problem
+                let _ = resizer.send(Ok(self.staff.size()));
+            }
+        }
+
+        // surplus workers are dismissed when they have been idle for too long
+        while self.staff.size() > self.min_size {
+            match self.staff.longest_idle() {
+                Some(since) if since + self.keep_alive <= Instant::now() => {
+                    let id = self.staff.idle[0];
+                    self.staff.dismiss(id);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Discards the jobs which have not been started if they cannot be run anymore.
+    fn clear_out(&mut self) {
+        // when propagating a panic the jobs which have not been started are discarded:
+        // they are dropped right away to not keep anyone waiting for them as a report would be lost to the re-raised panic anyway
+        if self.propagating && !self.aborting {
+            self.discarded_jobs.clear();
+            self.timers
+                .drain(Delayed::CANCELLED)
+                .chain(self.backlog.drain())
+                .for_each(drop);
+        }
+
+        // without workers the jobs which have not been started are discarded:
+        // they are only kept for the report of a pending shutdown to not keep anyone waiting for them
+        if self.max_size == 0 && !self.propagating && !self.aborting {
+            let tasks = self
+                .timers
+                .drain(Delayed::CANCELLED)
+                .chain(self.backlog.drain());
+            if self.shutdown.is_some() {
+                self.discarded_jobs.extend(tasks.map(into_job));
+            } else {
+                tasks.for_each(drop);
+            }
+        }
+    }
+
+    /// Queues the delayed jobs which are due (or are dealt with as configured on shutdown).
+    fn release_timers(&mut self) {
+        let shutting_down = self.shutdown.is_some() || self.closing;
+
+        // once shutting down, delayed jobs which are not due are dealt with as configured
+        if shutting_down {
+            match self.timer_shutdown {
+                TimerShutdown::Discard => {
+                    self.discarded_jobs
+                        .extend(self.timers.drain(Delayed::CANCELLED).map(into_job));
+                }
+                TimerShutdown::Fire => {
+                    for task in self.timers.drain(Delayed::RELEASED) {
+                        self.staff
+                            .contract
+                            .observers
+                            .notify(|observer| observer.job_submitted());
+                        self.backlog.hold(Priority::Normal, task);
+                    }
+                }
+                TimerShutdown::Wait => (),
+            }
+        }
+
+        // delayed jobs which are due are queued
+        for task in self.timers.release_due(Instant::now(), shutting_down) {
+            self.staff
+                .contract
+                .observers
+                .notify(|observer| observer.job_submitted());
+            self.backlog.hold(Priority::Normal, task);
+        }
+    }
+
+    /// Discards the jobs which have not been started once the deadline of a shutdown has passed.
+    fn enforce_deadline(&mut self) {
+        // past the deadline of a shutdown no more jobs are started
+        if let Some((_, Some(discard_from))) = self.shutdown {
+            if Instant::now() >= discard_from {
+                self.discarded_jobs
+                    .extend(self.timers.drain(Delayed::CANCELLED).map(into_job));
+                self.discarded_jobs
+                    .extend(self.backlog.drain().map(into_job));
+            }
+        }
+    }
+
+    /// Assigns pending jobs to idle workers and hires additional workers for the jobs no one is there to do (unless the distribution has stopped).
+    fn dispatch(&mut self) {
+        if self.halted() {
+            return;
+        }
+
+        while let Some(id) = self.staff.idle.pop() {
+            match self.backlog.next() {
+                Some(task) => self.staff.assign(id, task),
+                None => {
+                    self.staff.idle.push(id);
+                    break;
+                }
+            }
+        }
+
+        // hire additional workers for the jobs no one is there to do
+        while self.staff.starting() < self.backlog.len() && self.staff.size() < self.max_size {
+            if self.staff.hire().is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Dismisses all workers of a pool which is shutting down on its own once the remaining jobs are done.
+    ///
+    /// The supervisor carries on to answer the orders of the owner until it is ordered to shut down as well.
+    fn wind_down(&mut self) {
+        if self.closing
+            && self.max_size > 0
+            && self.staff.all_idle()
+            && self.backlog.is_empty()
+            && self.timers.is_empty()
+        {
+            self.staff.dismiss_all();
+            self.min_size = 0;
+            self.max_size = 0;
+        }
+    }
+
+    /// Publishes the figures of the pool on the noticeboard.
+    fn publish(&self) {
+        let published = &self.published;
+        // the figures are only read for information so there is no need to order them
+        published
+            .min_size
+            .store(self.min_size, AtomicOrdering::Relaxed);
+        published
+            .idle
+            .store(self.staff.idle.len(), AtomicOrdering::Relaxed);
+        published
+            .busy
+            .store(self.staff.busy(), AtomicOrdering::Relaxed);
+        published
+            .held
+            .store(self.backlog.held(), AtomicOrdering::Relaxed);
+        published
+            .completed
+            .store(self.completed_jobs, AtomicOrdering::Relaxed);
+        published
+            .panicked
+            .store(self.panicked_jobs, AtomicOrdering::Relaxed);
+        published
+            .respawns
+            .store(self.respawns, AtomicOrdering::Relaxed);
+        published
+            .size
+            .store(self.staff.size(), AtomicOrdering::SeqCst);
+    }
+
+    /// Tells the joiners if the pool is idle and tells whether the pool is done.
+    fn finish_round(&mut self) -> bool {
+        // the pool is idle if every worker is and there is nothing left to do
+        if self.staff.all_idle() && (self.backlog.is_empty() || self.halted()) {
+            for joiner in self.joiners.drain(..) {
+                // the joiner may have gone which is not a problem
+                let _ = joiner.send(());
+            }
+
+            if (self.shutdown.is_some() && self.timers.is_empty()) || self.aborting {
+                self.published.closed.store(true, AtomicOrdering::SeqCst);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Dismisses all workers and reports back to whoever ordered the shutdown (or kills the process).
+    fn terminate(mut self) {
+        // destruct all remaining worker-threads
+        self.staff.dismiss_all();
+
+        let stats = PoolStats {
+            configured_workers: self.min_size,
+            queued_jobs: self.backlog.len(),
+            completed_jobs: self.completed_jobs,
+            panicked_jobs: self.panicked_jobs,
+            evicted_jobs: self.published.evicted.load(AtomicOrdering::Relaxed),
+            respawns: self.respawns,
+            escalated: self.escalated,
+            ..PoolStats::default()
+        };
+        self.staff
+            .contract
+            .observers
+            .notify(|observer| observer.pool_terminated(&stats));
+
+        if self.aborting {
+            #[cfg(feature = "tracing")]
+            tracing::error!(panicked_jobs = self.panicked_jobs, "Aborting process.");
+            #[cfg(not(feature = "tracing"))]
+            eprintln!("Aborting process: {} panicked jobs.", self.panicked_jobs);
+            std::process::abort();
+        }
+
+        if let Some((report_s, _)) = self.shutdown {
+            let report = ShutdownReport {
+                completed: self.completed_jobs,
+                panicked: self.panicked_jobs,
+                discarded: self.discarded_jobs,
+            };
+            // the pool may have gone which is not a problem
+            let _ = report_s.send(report);
+        }
+
+        // ensure that `orders_r` and the job queue live as long as the thread to prevent reachability-errors
+        drop(self.orders_r);
+        drop(self.backlog);
+    }
+}
+
+/// [`Backlog`] is the supervisor's view of the job queue.
+struct Backlog {
+    /// places to take jobs of each [`Priority`] from (indexed by priority)
+    lanes: Vec<Receiver<Box<dyn Task>>>,
+    /// jobs of each priority queued by the supervisor itself (indexed by priority)
+    held: Vec<VecDeque<Box<dyn Task>>>,
+    /// how often pending jobs of each priority have been passed over
+    skipped: Vec<usize>,
+    /// how often pending jobs may be passed over
+    aging: usize,
+    /// place to free the slot of a job taken from the queue (only if the queue is bounded)
+    slots_r: Option<Receiver<()>>,
+    /// place where the stubs of cancelled jobs are accounted for
+    noticeboard: Arc<Noticeboard>,
+}
+
+/// Frees the slot of `task` which has been taken from the queue and tells whether it is to be run, that is, whether it has not been withdrawn.
+/// - `task` is the job taken from the queue.
+/// - `slots_r` is the place to free the slot (if the queue is bounded).
+/// - `noticeboard` is where the stubs of cancelled jobs are accounted for.
+fn dequeue(task: &dyn Task, slots_r: &Option<Receiver<()>>, noticeboard: &Noticeboard) -> bool {
+    let withdrawn = task.withdrawn();
+    // the slot of a withdrawn job may have been taken over by a new job already
+    if !withdrawn || noticeboard.take_spare_slot() {
+        if let Some(slots_r) = slots_r {
+            let _ = slots_r.try_recv();
+        }
+    }
+    !withdrawn
+}
+
+impl Backlog {
+    /// Sets up a backlog.
+    /// - `lanes` are the places to take jobs of each priority from.
+    /// - `slots_r` is the place to free the slots of the jobs taken from the lanes (if the queue is bounded).
+    /// - `aging` is how often pending jobs may be passed over by jobs of higher priority.
+    /// - `noticeboard` is where the stubs of cancelled jobs are accounted for.
+    fn new(
+        lanes: Vec<Receiver<Box<dyn Task>>>,
+        slots_r: Option<Receiver<()>>,
+        aging: usize,
+        noticeboard: Arc<Noticeboard>,
+    ) -> Self {
+        let held = (0..lanes.len()).map(|_| VecDeque::new()).collect();
+        let skipped = vec![0; lanes.len()];
+        Self {
+            lanes,
+            held,
+            skipped,
+            aging,
+            slots_r,
+            noticeboard,
+        }
+    }
+
+    /// Tells how many jobs are pending apart from the stubs of cancelled jobs.
+    fn len(&self) -> usize {
+        (self.lanes.iter().map(Receiver::len).sum::<usize>()
+            + self.held.iter().map(VecDeque::len).sum::<usize>())
+        .saturating_sub(self.noticeboard.withdrawn.load(AtomicOrdering::SeqCst))
+    }
+
+    /// Tells whether no job is pending.
+    fn is_empty(&self) -> bool {
+        (0..self.lanes.len()).all(|level| !self.is_pending(level))
+    }
+
+    /// Tells how many jobs are queued by the supervisor itself.
+    fn held(&self) -> usize {
+        self.held.iter().map(VecDeque::len).sum()
+    }
+
+    /// Tells whether jobs of the priority with index `level` are pending.
+    fn is_pending(&self, level: usize) -> bool {
+        !self.held[level].is_empty() || !self.lanes[level].is_empty()
+    }
+
+    /// Queues `task` with `priority` on behalf of the supervisor.
+    fn hold(&mut self, priority: Priority, task: Box<dyn Task>) {
+        self.held[priority as usize].push_back(task);
+    }
+
+    /// Takes the pending job of highest priority unless pending jobs of lower priority have been passed over too often.
+    /// In that case, the oldest of those jobs of lowest priority is taken.
+    ///
+    /// The stubs of cancelled jobs are dropped on the way.
+    fn next(&mut self) -> Option<Box<dyn Task>> {
+        let (level, task) = loop {
+            let pending = |level: &usize| self.is_pending(*level);
+
+            let starving = (0..self.lanes.len())
+                .filter(pending)
+                .find(|&level| self.aging > 0 && self.skipped[level] >= self.aging);
+            let level = starving.or_else(|| (0..self.lanes.len()).rev().find(pending))?;
+
+            match self.held[level].pop_front() {
+                Some(task) => break (level, task),
+                None => {
+                    let task = self.lanes[level].try_recv().ok()?;
+                    if dequeue(&*task, &self.slots_r, &self.noticeboard) {
+                        break (level, task);
+                    }
+                }
+            }
+        };
+
+        // every other level with pending jobs has been passed over
+        self.skipped[level] = 0;
+        for other in 0..self.lanes.len() {
+            if other != level && self.is_pending(other) {
+                self.skipped[other] += 1;
+            }
+        }
+
+        Some(task)
+    }
+
+    /// Takes all pending jobs from the highest priority to the lowest and drops the stubs of cancelled jobs.
+    fn drain(&mut self) -> impl Iterator<Item = Box<dyn Task>> + '_ {
+        self.skipped.iter_mut().for_each(|skipped| *skipped = 0);
+        let slots_r = &self.slots_r;
+        let noticeboard = &self.noticeboard;
+        self.held
+            .iter_mut()
+            .zip(self.lanes.iter())
+            .rev()
+            .flat_map(move |(held, lane)| {
+                let taken = lane
+                    .try_iter()
+                    .filter(move |task| dequeue(&**task, slots_r, noticeboard));
+                held.drain(..).chain(taken)
+            })
+    }
+}
+
+/// [`Delayed`] is a job waiting to be due.
+struct Delayed {
+    /// id of the job
+    id: u64,
+    /// what has become of the job (shared with its [`Timer`])
+    state: Arc<AtomicU8>,
+    /// whether the job reschedules itself
+    recurring: bool,
+    /// the job
+    task: Box<dyn Task>,
+}
+
+impl Delayed {
+    /// state of a job which is not due yet
+    const PENDING: u8 = 0;
+    /// state of a job which has been queued to run (for the last time)
+    const RELEASED: u8 = 1;
+    /// state of a job which has been cancelled or discarded
+    const CANCELLED: u8 = 2;
+
+    /// Settles the state of `self` unless it has been cancelled and hands out the job in that case.
+    /// - `state` is the final state of `self`.
+    fn settle(self, state: u8) -> Option<Box<dyn Task>> {
+        self.state
+            .compare_exchange(
+                Self::PENDING,
+                state,
+                AtomicOrdering::SeqCst,
+                AtomicOrdering::SeqCst,
+            )
+            .ok()
+            .map(|_| self.task)
+    }
+}
+
+/// [`Recurring`] is a job which reschedules itself after each run.
+struct Recurring<F> {
+    /// the job
+    f: F,
+    /// when the current run is due
+    due: Instant,
+    /// time between two runs
+    period: Duration,
+    /// when the next run is due
+    cadence: Cadence,
+    /// whether to go on after a panicked run
+    on_panic: RecurringPanic,
+    /// id of the job
+    id: u64,
+    /// what has become of the job (shared with its [`Timer`])
+    state: Arc<AtomicU8>,
+    /// place to put the next run
+    orders_s: Sender<Message>,
+    /// place to record the latencies of each run (if they are recorded)
+    times: Option<Arc<JobTimes>>,
+    /// the span the job has been ordered in (which each run continues)
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl<F> Recurring<F>
+where
+    F: FnMut() + UnwindSafe + Send + 'static,
+{
+    /// Wraps `self` into a [`Delayed`] job which runs `self` when due.
+    fn into_delayed(self) -> Delayed {
+        let id = self.id;
+        let state = Arc::clone(&self.state);
+        // each run waits for a worker from when it is due on
+        let due = self.due;
+        let times = self.times.clone();
+        #[cfg(feature = "tracing")]
+        let span = self.span.clone();
+
+        let task: Box<dyn Task> = Box::new(AssertUnwindSafe(move || self.run()));
+        #[cfg(feature = "tracing")]
+        let task = Traced::wrap(task, span);
+
+        Delayed {
+            id,
+            state,
+            recurring: true,
+            task: Timed::wrap(task, due, &times),
+        }
+    }
+
+    /// Runs the job and schedules the next run unless the recurrence is over.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the job panics (after the next run has been scheduled if it is supposed to go on).
+    fn run(mut self) {
+        let outcome = std::panic::catch_unwind(AssertUnwindSafe(&mut self.f));
+
+        let going_on = outcome.is_ok() || self.on_panic == RecurringPanic::Continue;
+        if going_on && self.state.load(AtomicOrdering::SeqCst) == Delayed::PENDING {
+            let now = Instant::now();
+            self.due = match self.cadence {
+                Cadence::FixedRate => {
+                    // skip the runs which have been missed
+                    let mut due = self.due + self.period;
+                    while due <= now && !self.period.is_zero() {
+                        due += self.period;
+                    }
+                    due
+                }
+                Cadence::FixedDelay => now + self.period,
+            };
+            let due = self.due;
+            // the pool may have gone which ends the recurrence
+            let _ = self
+                .orders_s
+                .clone()
+                .send(Message::Schedule(due, self.into_delayed()));
+        } else {
+            // the recurrence is over
+            let _ = self.state.compare_exchange(
+                Delayed::PENDING,
+                Delayed::RELEASED,
+                AtomicOrdering::SeqCst,
+                AtomicOrdering::SeqCst,
+            );
+        }
+
+        if let Err(payload) = outcome {
+            std::panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// [`Timers`] are the supervisor's record of the delayed jobs.
+struct Timers {
+    /// when the jobs are due (earliest first)
+    schedule: BinaryHeap<Reverse<(Instant, u64)>>,
+    /// the jobs by their ids
+    delayed: HashMap<u64, Delayed>,
+}
+
+impl Timers {
+    /// Sets up an empty record.
+    fn new() -> Self {
+        Self {
+            schedule: BinaryHeap::new(),
+            delayed: HashMap::new(),
+        }
+    }
+
+    /// Tells whether no job is waiting to be due.
+    fn is_empty(&self) -> bool {
+        self.delayed.is_empty()
+    }
+
+    /// Tells when the next job is due.
+    fn next_due(&mut self) -> Option<Instant> {
+        // forget cancelled jobs on the way
+        while let Some(Reverse((at, id))) = self.schedule.peek() {
+            if self.delayed.contains_key(id) {
+                return Some(*at);
+            }
+            self.schedule.pop();
+        }
+        None
+    }
+
+    /// Records `delayed` to be due `at`.
+    fn schedule(&mut self, at: Instant, delayed: Delayed) {
+        self.schedule.push(Reverse((at, delayed.id)));
+        self.delayed.insert(delayed.id, delayed);
+    }
+
+    /// Forgets the job with `id`.
+    fn cancel(&mut self, id: u64) {
+        self.delayed.remove(&id);
+    }
+
+    /// Hands out the jobs which are due at `now` (earliest first).
+    /// - `last` tells whether recurring jobs are supposed to stop after this run.
+    fn release_due(&mut self, now: Instant, last: bool) -> Vec<Box<dyn Task>> {
+        let mut due = Vec::new();
+        while let Some(Reverse((at, id))) = self.schedule.peek() {
+            if *at > now {
+                break;
+            }
+            if let Some(delayed) = self.delayed.remove(id) {
+                let state = if delayed.recurring && !last {
+                    Delayed::PENDING
+                } else {
+                    Delayed::RELEASED
+                };
+                due.extend(delayed.settle(state));
+            }
+            self.schedule.pop();
+        }
+        due
+    }
+
+    /// Hands out all jobs (earliest first) which have not been cancelled and settles them in `state`.
+    fn drain(&mut self, state: u8) -> impl Iterator<Item = Box<dyn Task>> {
+        let schedule = std::mem::take(&mut self.schedule).into_sorted_vec();
+        let mut delayed = std::mem::take(&mut self.delayed);
+        schedule
+            .into_iter()
+            .rev()
+            .filter_map(move |Reverse((_, id))| delayed.remove(&id))
+            .filter_map(move |delayed| delayed.settle(state))
+    }
+}
+
+/// [`Noticeboard`]s are where the supervisor-thread publishes information on the pool.
+#[derive(Default)]
+struct Noticeboard {
+    /// number of workers the pool is supposed to have at least
+    min_size: AtomicUsize,
+    /// number of employed workers
+    size: AtomicUsize,
+    /// number of workers waiting for instructions
+    idle: AtomicUsize,
+    /// number of workers running jobs
+    busy: AtomicUsize,
+    /// number of jobs the supervisor holds back for the workers
+    held: AtomicUsize,
+    /// number of stubs of cancelled jobs left in the job queue
+    withdrawn: AtomicUsize,
+    /// number of slots of the job queue taken up by those stubs which have not been taken over by new jobs
+    spare_slots: AtomicUsize,
+    /// number of jobs which have been finished
+    completed: AtomicUsize,
+    /// number of jobs which have panicked
+    panicked: AtomicUsize,
+    /// number of jobs which have been discarded to make room for others
+    evicted: AtomicUsize,
+    /// number of workers which have been respawned
+    respawns: AtomicUsize,
+    /// whether the pool has stopped accepting jobs
+    closed: AtomicBool,
+    /// whether the pool has exceeded its restart intensity
+    escalated: AtomicBool,
+    /// panic of the first panicked job which is yet to be re-raised (in propagate-mode)
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl Noticeboard {
+    /// Takes over a slot of the job queue from the stub of a cancelled job and tells whether there has been one.
+    fn take_spare_slot(&self) -> bool {
+        self.spare_slots
+            .fetch_update(AtomicOrdering::SeqCst, AtomicOrdering::SeqCst, |spare| {
+                spare.checked_sub(1)
+            })
+            .is_ok()
+    }
+}
+
+/// [`Staff`] is the supervisor's record of its workers.
+struct Staff {
+    /// workers by staff number (`None` for vacancies)
+    workers: Vec<Option<Worker>>,
+    /// staff numbers of the workers waiting for instructions (from the longest to the shortest waiting)
+    idle: Vec<StaffNumber>,
+    /// place where the workers put their statuses
+    statuses_s: Sender<Status>,
+    /// terms on which the workers are employed
+    contract: Arc<Contract>,
+}
+
+impl Staff {
+    /// Sets up a staff without workers.
+    /// - `statuses_s` is where the workers put their statuses.
+    /// - `contract` specifies the terms on which the workers are employed.
+    fn new(statuses_s: Sender<Status>, contract: Arc<Contract>) -> Self {
+        Self {
+            workers: Vec::new(),
+            idle: Vec::new(),
+            statuses_s,
+            contract,
+        }
+    }
+
+    /// Counts the employed workers.
+    fn size(&self) -> usize {
+        self.workers.iter().flatten().count()
+    }
+
+    /// Counts the workers which are running jobs.
+    fn busy(&self) -> usize {
+        self.workers
+            .iter()
+            .flatten()
+            .filter(|worker| matches!(worker.duty, Duty::Busy))
+            .count()
+    }
+
+    /// Counts the workers which have not reported for duty yet.
+    fn starting(&self) -> usize {
+        self.workers
+            .iter()
+            .flatten()
+            .filter(|worker| matches!(worker.duty, Duty::Starting))
+            .count()
+    }
+
+    /// Tells since when the worker which has been waiting for instructions for the longest time is idle.
+    fn longest_idle(&self) -> Option<Instant> {
+        let id = self.idle.first()?;
+        match self.workers[*id].as_ref()?.duty {
+            Duty::Idle(since) => Some(since),
+            _ => None,
+        }
+    }
+
+    /// Checks if all employed workers are waiting for instructions.
+    fn all_idle(&self) -> bool {
+        self.idle.len() == self.size()
+    }
+
+    /// Hires a new worker for the first vacancy.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the worker-thread could not be spawned.
+    fn hire(&mut self) -> io::Result<StaffNumber> {
+        let id = self
+            .workers
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(self.workers.len());
+
+        self.hire_as(id)?;
+        self.contract
+            .observers
+            .notify(|observer| observer.worker_spawned(id));
+        Ok(id)
+    }
+
+    /// Hires a new worker for the vacancy `id`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the worker-thread could not be spawned.
+    fn hire_as(&mut self, id: StaffNumber) -> io::Result<()> {
+        let worker = Worker::new(id, self.statuses_s.clone(), Arc::clone(&self.contract))?;
+
+        if id == self.workers.len() {
+            self.workers.push(None);
+        }
+        self.workers[id] = Some(worker);
+
+        Ok(())
+    }
+
+    /// Gives the job `task` to the idle worker `id`.
+    fn assign(&mut self, id: StaffNumber, task: Box<dyn Task>) {
+        let worker = self.workers[id].as_mut().unwrap();
+        worker.duty = Duty::Busy;
+        worker.instructions_s.send(Message::NewJob(task)).unwrap();
+    }
+
+    /// Notes that the worker `id` waits for instructions and tells if it has finished a job.
+    fn report_idle(&mut self, id: StaffNumber) -> bool {
+        self.idle.push(id);
+
+        let worker = self.workers[id].as_mut().unwrap();
+        let duty = std::mem::replace(&mut worker.duty, Duty::Idle(Instant::now()));
+        matches!(duty, Duty::Busy)
+    }
+
+    /// Vacates the position of the worker `id` whose thread is ending on its own.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if joining the worker-thread panics.
+    fn release(&mut self, id: StaffNumber) {
+        if let Some(mut worker) = self.workers[id].take() {
+            thread::join(&mut worker.thread);
+        }
+    }
+
+    /// Dismisses the idle worker `id` and vacates its position.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the worker is unreachable.
+    fn dismiss(&mut self, id: StaffNumber) {
+        self.idle.retain(|&idle_id| idle_id != id);
+
+        if let Some(mut worker) = self.workers[id].take() {
+            worker.dismiss();
+            self.contract
+                .observers
+                .notify(|observer| observer.worker_dismissed(id));
+        }
+    }
+
+    /// Dismisses all workers which are supposed to be idle.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if a worker is unreachable.
+    fn dismiss_all(&mut self) {
+        for id in 0..self.workers.len() {
+            self.dismiss(id);
+        }
+    }
+}
+
+/// [`Contract`]s are the terms on which workers are employed.
+struct Contract {
+    /// prefix of the worker-threads' names
+    name_prefix: String,
+    /// stack size of the worker-threads in bytes
+    stack_size: Option<usize>,
+    /// what a worker-thread does first
+    on_thread_start: Option<Hook>,
+    /// what a worker-thread does last
+    on_thread_stop: Option<Hook>,
+    /// who is told about what happens to the jobs
+    observers: Observers,
+}
+
+/// [`Worker`] abstracts workers.
+struct Worker {
+    /// place to put instructions
+    instructions_s: Sender<Message>,
+    /// handle to join
+    thread: JoinHandle,
+    /// what the worker is doing according to the supervisor
+    duty: Duty,
+}
+
+/// [`Duty`] is what a [`Worker`] is doing according to the supervisor.
+enum Duty {
+    /// The worker has not reported for duty yet.
+    Starting,
+    /// The worker is waiting for instructions since the given time.
+    Idle(Instant),
+    /// The worker has been given a job it has not reported back on yet.
+    Busy,
+}
+
+impl Worker {
+    /// Sets up a new worker.
+    /// - `id` is the worker's staff number.
+    /// - `statuses_s` is where the worker puts its current status.
+    /// - `contract` specifies the terms of employment.
+    ///
+    /// In particular, it spawns a thread and sets up a way to communicate to the thread.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the thread could not be spawned.
+    fn new(
+        id: StaffNumber,
+        statuses_s: Sender<Status>,
+        contract: Arc<Contract>,
+    ) -> io::Result<Self> {
+        // this channel is used by the supervisor to contact this worker
+        let (instructions_s, instructions_r) = channel();
+
+        let mut builder =
+            std::thread::Builder::new().name(format!("{}-{}", contract.name_prefix, id));
+        if let Some(size) = contract.stack_size {
+            builder = builder.stack_size(size);
+        }
+
+        let thread = thread::spawn(builder, move || {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::info_span!("worker", worker = id).entered();
+
+            // get ready
+            if let Some(hook) = &contract.on_thread_start {
+                if std::panic::catch_unwind(AssertUnwindSafe(|| hook(id))).is_err() {
+                    statuses_s.send(Status::Unready(id)).unwrap();
+                    return;
+                }
+            }
+
+            // report for duty
+            statuses_s.send(Status::Idle(id)).unwrap();
+
+            // keepin' running to execute jobs
+            loop {
+                let message = instructions_r.recv().unwrap();
+
+                match message {
+                    Message::NewJob(task) => {
+                        let observers = &contract.observers;
+                        // the time is only taken if someone is interested
+                        let started = (!observers.is_empty()).then(Instant::now);
+                        observers.notify(|observer| observer.job_started(id));
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(worker = id, "Started job.");
+
+                        match std::panic::catch_unwind(move || task.run(Some(id))) {
+                            Ok(_) => {
+                                #[cfg(feature = "tracing")]
+                                tracing::debug!(worker = id, "Finished job.");
+                                if let Some(started) = started {
+                                    let duration = started.elapsed();
+                                    observers
+                                        .notify(|observer| observer.job_finished(id, duration));
+                                }
+                                statuses_s.send(Status::Idle(id)).unwrap();
+                            }
+                            Err(payload) => {
+                                let (payload, handoff) = Panicked::split(payload);
+                                #[cfg(feature = "tracing")]
+                                tracing::error!(worker = id, "Job panicked.");
+                                observers.notify(|observer| observer.job_panicked(id, &*payload));
+                                statuses_s
+                                    .send(Status::Panic(id, payload, handoff))
+                                    .unwrap();
+                                break;
+                            }
+                        }
+                    }
+                    Message::Terminate => break,
+                    Message::Schedule(_, _)
+                    | Message::Cancel(_)
+                    | Message::Join(_)
+                    | Message::Resize(_, _)
+                    | Message::Shutdown(_, _) => {
+                        unreachable!("Workers are only ordered to run jobs or to terminate.")
+                    }
+                }
+            }
+
+            // clean up
+            if let Some(hook) = &contract.on_thread_stop {
+                let _ = std::panic::catch_unwind(AssertUnwindSafe(|| hook(id)));
+            }
+        })?;
+
+        Ok(Self {
+            instructions_s,
+            thread,
+            duty: Duty::Starting,
+        })
+    }
+
+    /// Orders the worker to terminate and waits for it to do so.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if the worker is unreachable or has already been joined.
+    fn dismiss(&mut self) {
+        self.instructions_s.send(Message::Terminate).unwrap();
+        thread::join(&mut self.thread);
+    }
 }
 
-impl fmt::Display for Status {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Self::Idle(_) => write!(f, "[idle]"),
-            Self::Panic(_) => write!(f, "[panic]"),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossbeam::channel::RecvTimeoutError;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier};
+
+    // settings
+    const SIZE: usize = 2; //= 6; && = 12; && = 36;
+    const MODE: PanicSwitch = PanicSwitch::Respawn; //= PanicSwitch::Kill;
+    const ID: StaffNumber = 0;
+    const N_SCOPED: usize = 10;
+
+    fn contract() -> Arc<Contract> {
+        Arc::new(Contract {
+            name_prefix: String::from("test-worker"),
+            stack_size: None,
+            on_thread_start: None,
+            on_thread_stop: None,
+            observers: Observers(Arc::new([])),
+        })
+    }
+
+    /// Builds a pool with `builder` and keeps one of its workers busy until the returned gate is dropped.
+    fn blocked_pool(builder: ThreadPoolBuilder) -> (ThreadPool, Sender<()>) {
+        let pool = builder.build().unwrap();
+
+        let (gate_s, gate_r) = bounded::<()>(0);
+        pool.execute(move || {
+            let _ = gate_r.recv();
+        });
+        while pool.queued_len() > 0 {
+            std::thread::yield_now();
+        }
+
+        (pool, gate_s)
+    }
+
+    /// Makes a job which announces that it has started and panics once the returned gate is dropped.
+    fn gated_panic() -> (impl FnOnce() + UnwindSafe + Send, Receiver<()>, Sender<()>) {
+        let (started_s, started_r) = bounded(1);
+        let (gate_s, gate_r) = bounded::<()>(0);
+        let job = move || {
+            started_s.send(()).unwrap();
+            let _ = gate_r.recv();
+            panic!("Oh no!");
+        };
+
+        (job, started_r, gate_s)
+    }
+
+    #[test]
+    #[allow(clippy::redundant_pattern_matching)]
+    fn test_threadpool_new_ok() {
+        let pool = ThreadPool::new(SIZE, MODE);
+        assert!(matches!(pool, Ok(_)));
+    }
+
+    #[test]
+    fn test_threadpool_new_err() {
+        let pool = ThreadPool::new(0, MODE);
+        assert!(matches!(pool, Err(Error::ZeroWorkers)));
+    }
+
+    #[test]
+    fn test_threadpoolbuilder_build() {
+        assert!(matches!(
+            ThreadPoolBuilder::new(0).build(),
+            Err(Error::ZeroWorkers)
+        ));
+        assert!(matches!(
+            ThreadPoolBuilder::new(SIZE).queue_capacity(0).build(),
+            Err(Error::ZeroCapacity)
+        ));
+
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(MODE)
+            .name_prefix("test-worker")
+            .supervisor_name("test-supervisor")
+            .stack_size(1024 * 1024)
+            .build()
+            .unwrap();
+
+        let mut names: Vec<String> = (0..SIZE)
+            .map(|_| {
+                pool.spawn(|| {
+                    std::thread::sleep(Duration::from_millis(10));
+                    std::thread::current().name().unwrap().to_string()
+                })
+            })
+            .collect::<Vec<JobHandle<String>>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+        names.sort();
+        names.dedup();
+
+        let expected: Vec<String> = (0..SIZE).map(|id| format!("test-worker-{}", id)).collect();
+        assert_eq!(expected, names);
+    }
+
+    #[test]
+    fn test_threadpoolbuilder_hooks() {
+        let started = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicUsize::new(0));
+
+        let started_ref = Arc::clone(&started);
+        let stopped_ref = Arc::clone(&stopped);
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(PanicSwitch::Respawn)
+            .on_thread_start(move |_| {
+                started_ref.fetch_add(1, Ordering::SeqCst);
+            })
+            .on_thread_stop(move |_| {
+                stopped_ref.fetch_add(1, Ordering::SeqCst);
+            })
+            .build()
+            .unwrap();
+
+        pool.join();
+        assert_eq!(SIZE, started.load(Ordering::SeqCst));
+
+        pool.execute(|| panic!("Oh no!"));
+        pool.join();
+        assert_eq!(SIZE + 1, started.load(Ordering::SeqCst));
+        assert_eq!(1, stopped.load(Ordering::SeqCst));
+
+        drop(pool);
+        assert_eq!(SIZE + 1, stopped.load(Ordering::SeqCst));
+
+        // a panicking hook does not make the pool respawn its worker-threads over and over again
+        let started = Arc::new(AtomicUsize::new(0));
+        let started_ref = Arc::clone(&started);
+        // the hooks panic once the gate opens
+        let (gate_s, gate_r) = bounded::<()>(0);
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(PanicSwitch::Respawn)
+            .on_thread_start(move |_| {
+                started_ref.fetch_add(1, Ordering::SeqCst);
+                let _ = gate_r.recv();
+                panic!("Oh no!");
+            })
+            .build()
+            .unwrap();
+
+        let handle = pool.spawn(|| 42);
+        drop(gate_s);
+        pool.join();
+        assert!(handle.join().is_err());
+        assert_eq!(SIZE, started.load(Ordering::SeqCst));
+        assert_eq!(0, pool.size());
+        let stats = pool.stats();
+        assert_eq!(0, stats.panicked_jobs);
+        assert_eq!(0, stats.respawns);
+        let rejected = pool.try_execute(|| ()).unwrap_err();
+        assert!(matches!(rejected.error(), Error::PoolShutDown));
+        assert_eq!(0, pool.shutdown(ShutdownMode::Graceful).panicked);
+    }
+
+    #[test]
+    fn test_threadpool_execute() {
+        const N: usize = 5;
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        let count_to = |n: usize| {
+            for _ in 0..n {
+                let counter = Arc::clone(&counter);
+                pool.execute(move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        };
+
+        for _ in 0..N {
+            count_to(SIZE);
+            if let PanicSwitch::Respawn = MODE {
+                pool.execute(|| panic!("Oh no!"));
+            }
+        }
+
+        drop(pool);
+
+        assert_eq!(N * SIZE, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_threadpool_try_execute() {
+        let mut pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let flag_ref = Arc::clone(&flag);
+        assert!(pool
+            .try_execute(move || flag_ref.store(true, Ordering::SeqCst))
+            .is_ok());
+        pool.join();
+        assert!(flag.load(Ordering::SeqCst));
+
+        pool.terminate(ShutdownMode::Graceful);
+
+        let flag_ref = Arc::clone(&flag);
+        let e = pool
+            .try_execute(move || flag_ref.store(false, Ordering::SeqCst))
+            .unwrap_err();
+        assert!(matches!(e.error(), Error::PoolShutDown));
+        assert!(flag.load(Ordering::SeqCst));
+        e.into_job()();
+        assert!(!flag.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_threadpool_join() {
+        const N: usize = 3;
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        for n in 1..=N {
+            for _ in 0..SIZE {
+                let counter = Arc::clone(&counter);
+                pool.execute(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+            if let PanicSwitch::Respawn = MODE {
+                pool.execute(|| panic!("Oh no!"));
+            }
+
+            pool.join();
+
+            assert_eq!(n * SIZE, counter.load(Ordering::SeqCst));
+        }
+
+        // joining an idle pool returns immediately
+        pool.join();
+    }
+
+    #[test]
+    fn test_threadpool_resize() {
+        const N: usize = 4;
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        assert!(matches!(pool.resize(0), Err(Error::ZeroWorkers)));
+
+        assert_eq!(N * SIZE, pool.resize(N * SIZE).unwrap());
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        for _ in 0..N * SIZE {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                std::thread::sleep(Duration::from_millis(20));
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        // shrinking does not lose any jobs
+        assert_eq!(1, pool.resize(1).unwrap());
+
+        assert_eq!(SIZE, pool.resize(SIZE).unwrap());
+        pool.join();
+        assert_eq!(N * SIZE, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_threadpool_elastic() {
+        const N: usize = 4;
+        const KEEP_ALIVE: Duration = Duration::from_millis(50);
+
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(MODE)
+            .max_size(N * SIZE)
+            .keep_alive(KEEP_ALIVE)
+            .build()
+            .unwrap();
+        assert_eq!(SIZE, pool.size());
+
+        // the jobs only finish once all of them run at the same time
+        let barrier = Arc::new(Barrier::new(N * SIZE + 1));
+        let counter = Arc::new(AtomicUsize::new(0));
+        for _ in 0..N * SIZE {
+            let barrier = Arc::clone(&barrier);
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                barrier.wait();
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        barrier.wait();
+        pool.join();
+
+        assert_eq!(N * SIZE, counter.load(Ordering::SeqCst));
+        assert_eq!(N * SIZE, pool.size());
+
+        // the surplus workers are dismissed once they have been idle for long enough
+        let patience = Instant::now() + Duration::from_secs(10);
+        while pool.size() > SIZE && Instant::now() < patience {
+            std::thread::sleep(KEEP_ALIVE);
+        }
+        assert_eq!(SIZE, pool.size());
+    }
+
+    #[test]
+    fn test_threadpool_shutdown() {
+        const N: usize = 4;
+
+        let sleepy_job = || std::thread::sleep(Duration::from_millis(50));
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+        for _ in 0..N * SIZE {
+            pool.execute(sleepy_job);
+        }
+        if let PanicSwitch::Respawn = MODE {
+            pool.execute(|| panic!("Oh no!"));
+        }
+        let report = pool.shutdown(ShutdownMode::Graceful);
+        assert_eq!(N * SIZE, report.completed);
+        if let PanicSwitch::Respawn = MODE {
+            assert_eq!(1, report.panicked);
+        }
+        assert!(report.discarded.is_empty());
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+        for _ in 0..N * SIZE {
+            pool.execute(sleepy_job);
+        }
+        let report = pool.shutdown(ShutdownMode::Deadline(Duration::from_millis(75)));
+        assert!(report.completed >= SIZE);
+        assert!(!report.discarded.is_empty());
+        assert_eq!(N * SIZE, report.completed + report.discarded.len());
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+        pool.join();
+        for _ in 0..N * SIZE {
+            pool.execute(sleepy_job);
+        }
+        let report = pool.shutdown(ShutdownMode::Immediate);
+        assert!(report.completed <= SIZE);
+        assert_eq!(N * SIZE, report.completed + report.discarded.len());
+        for job in report.discarded {
+            job();
+        }
+    }
+
+    #[test]
+    fn test_threadpool_scope() {
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let mut numbers: Vec<usize> = (0..N_SCOPED).collect();
+        let total = AtomicUsize::new(0);
+
+        let returned = pool.scope(|s| {
+            for chunk in numbers.chunks_mut(SIZE) {
+                let total = &total;
+                s.execute(move || {
+                    std::thread::sleep(Duration::from_millis(1));
+                    for n in chunk.iter_mut() {
+                        total.fetch_add(*n, Ordering::SeqCst);
+                        *n *= 2;
+                    }
+                });
+            }
+            "done"
+        });
+
+        assert_eq!("done", returned);
+        assert_eq!((0..N_SCOPED).sum::<usize>(), total.load(Ordering::SeqCst));
+        assert_eq!(
+            (0..N_SCOPED).map(|n| 2 * n).collect::<Vec<usize>>(),
+            numbers
+        );
+
+        if let PanicSwitch::Respawn = MODE {
+            let finished = AtomicBool::new(false);
+            let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                pool.scope(|s| {
+                    s.execute(|| panic!("Oh no!"));
+                    s.execute(|| {
+                        std::thread::sleep(Duration::from_millis(10));
+                        finished.store(true, Ordering::SeqCst);
+                    });
+                })
+            }));
+            // the original panic is re-raised
+            let payload = result.unwrap_err();
+            assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+            assert!(finished.load(Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn test_scoped_job_drop_order() {
+        /// Records on drop whether the report of its job has not been sent by then.
+        struct Witness<'a>(&'a Receiver<Outcome>, &'a AtomicBool);
+
+        impl Drop for Witness<'_> {
+            fn drop(&mut self) {
+                self.1.store(self.0.is_empty(), Ordering::SeqCst);
+            }
+        }
+
+        let (outcomes_s, outcomes_r) = channel();
+        let in_time = AtomicBool::new(false);
+        let scoped_job = |f| ScopedJob {
+            f: Some(f),
+            report: Some(Report {
+                outcomes_s: outcomes_s.clone(),
+                outcome: Outcome::Panicked(None),
+            }),
+        };
+
+        // dropped without being run, e.g. when it is cleared out or evicted
+        let witness = Witness(&outcomes_r, &in_time);
+        drop(scoped_job(
+            Box::new(move || drop(witness)) as Box<dyn FnOnce() + Send + '_>
+        ));
+        assert!(in_time.swap(false, Ordering::SeqCst));
+        assert!(matches!(outcomes_r.recv(), Ok(Outcome::Panicked(None))));
+
+        // finished
+        let witness = Witness(&outcomes_r, &in_time);
+        scoped_job(Box::new(move || drop(witness))).run();
+        assert!(in_time.swap(false, Ordering::SeqCst));
+        assert!(matches!(outcomes_r.recv(), Ok(Outcome::Finished)));
+
+        // panicked with the payload handed over by the supervisor
+        let witness = Witness(&outcomes_r, &in_time);
+        let job = scoped_job(Box::new(move || {
+            let _witness = witness;
+            panic!("Oh no!");
+        }));
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| job.run())).unwrap_err();
+        assert!(in_time.swap(false, Ordering::SeqCst));
+        let (payload, handoff) = Panicked::split(payload);
+        assert!(outcomes_r.is_empty());
+        handoff.unwrap()(payload);
+        match outcomes_r.recv() {
+            Ok(Outcome::Panicked(Some(payload))) => {
+                assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>())
+            }
+            _ => panic!("Panicked scoped job has not reported its payload."),
         }
     }
-}
 
-/// [`Supervisor`] abstracts the supervisors.
-struct Supervisor {
-    /// place to put orders
-    orders_s: Sender<Message>,
-    /// handle to join
-    thread: JoinHandle,
-}
+    #[test]
+    fn test_threadpool_queue_capacity() {
+        const CAPACITY: usize = 2;
 
-impl Supervisor {
-    /// Sets up a supervisor.
-    /// - `number_of_workers` is how many workers are employed.
-    /// - `mode` configures what happens when workers report panicking jobs.
-    ///
-    /// In particular, it spawns a thread and sets up a way to communicate to the thread.
-    /// Moreover, it creates the workers controlled by the just spawned supervisor-thread.
-    fn new(mut number_of_workers: usize, mode: PanicSwitch) -> Self {
-        // this channel is used by the pool to contact the supervisor
-        let (orders_s, orders_r) = channel();
+        let setup = |policy: RejectionPolicy| {
+            blocked_pool(
+                ThreadPoolBuilder::new(1)
+                    .panic_switch(MODE)
+                    .queue_capacity(CAPACITY)
+                    .rejection_policy(policy),
+            )
+        };
 
-        let thread = thread::spawn(move || {
-            // this channel is used by the workers to contact the supervisor
-            let (statuses_s, statuses_r) = channel();
-
-            // construct `number_of_workers` worker-threads
-            let mut workers = Vec::with_capacity(number_of_workers);
-            for id in 0..number_of_workers {
-                workers.push(Worker::new(id, statuses_s.clone()));
-            }
-
-            // track how many jobs have panicked
-            let mut panicked_jobs = 0;
-
-            // keepin' running to distribute jobs among idle workers
-            'distribute_jobs: while let Message::NewJob(job) = orders_r.recv().unwrap() {
-                'query_status: loop {
-                    match statuses_r.recv().unwrap() {
-                        Status::Idle(id) => {
-                            workers[id]
-                                .instructions_s
-                                .send(Message::NewJob(job))
-                                .unwrap();
-                            break 'query_status;
-                        }
-                        Status::Panic(id) => {
-                            thread::join(&mut workers[id].thread);
-                            match mode {
-                                PanicSwitch::Kill => {
-                                    panicked_jobs += 1;
-                                    number_of_workers -= 1;
-                                    break 'distribute_jobs;
-                                }
-                                PanicSwitch::Respawn => {
-                                    workers[id] = Worker::new(id, statuses_s.clone());
-                                }
-                            };
-                        }
-                    }
+        let counter = Arc::new(AtomicUsize::new(0));
+        let count = |counter: &Arc<AtomicUsize>, n: usize| {
+            let counter = Arc::clone(counter);
+            move || {
+                counter.fetch_add(n, Ordering::SeqCst);
+            }
+        };
+
+        let (pool, gate_s) = setup(RejectionPolicy::Reject);
+        for _ in 0..CAPACITY {
+            assert!(pool.try_execute(count(&counter, 1)).is_ok());
+        }
+        assert_eq!(CAPACITY, pool.queued_len());
+        let e = pool.try_execute(count(&counter, 1)).unwrap_err();
+        assert!(matches!(e.error(), Error::QueueFull));
+        // the capacity is shared by all priorities
+        for priority in [Priority::Low, Priority::High] {
+            let e = pool
+                .try_execute_with_priority(priority, count(&counter, 1))
+                .unwrap_err();
+            assert!(matches!(e.error(), Error::QueueFull));
+        }
+        // the rejected job is not dropped silently
+        let payload =
+            std::panic::catch_unwind(AssertUnwindSafe(|| pool.execute(count(&counter, 1))))
+                .unwrap_err();
+        assert_eq!(
+            Some("Ordering [NewJob] failed. Job queue is full."),
+            payload.downcast_ref::<String>().map(String::as_str)
+        );
+        drop(gate_s);
+        pool.join();
+        assert_eq!(CAPACITY, counter.swap(0, Ordering::SeqCst));
+
+        let (pool, gate_s) = setup(RejectionPolicy::CallerRuns);
+        for _ in 0..CAPACITY {
+            pool.execute(count(&counter, 1));
+        }
+        pool.execute(count(&counter, 1));
+        assert_eq!(1, counter.load(Ordering::SeqCst));
+        drop(gate_s);
+        pool.join();
+        assert_eq!(CAPACITY + 1, counter.swap(0, Ordering::SeqCst));
+
+        let (pool, gate_s) = setup(RejectionPolicy::DropOldest);
+        pool.execute(count(&counter, 1));
+        for _ in 0..CAPACITY {
+            pool.execute(count(&counter, 10));
+        }
+        assert_eq!(CAPACITY, pool.queued_len());
+        drop(gate_s);
+        pool.join();
+        assert_eq!(10 * CAPACITY, counter.swap(0, Ordering::SeqCst));
+        let stats = pool.stats();
+        assert_eq!(1, stats.evicted_jobs);
+        // every job ordered (including the one keeping the worker busy) is accounted for
+        assert_eq!(
+            CAPACITY + 2,
+            stats.completed_jobs + stats.panicked_jobs + stats.evicted_jobs
+        );
+
+        let (pool, gate_s) = setup(RejectionPolicy::Block);
+        let (done_s, done_r) = bounded(1);
+        let counter_ref = Arc::clone(&counter);
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for _ in 0..=CAPACITY {
+                    pool.execute(count(&counter_ref, 1));
+                }
+                done_s.send(()).unwrap();
+            });
+            assert!(done_r.recv_timeout(Duration::from_millis(50)).is_err());
+            drop(gate_s);
+            done_r.recv().unwrap();
+        });
+        pool.join();
+        assert_eq!(CAPACITY + 1, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_threadpool_priority() {
+        const AGING: usize = 3;
+
+        // the worker is kept busy until everything is queued
+        let (pool, gate_s) =
+            blocked_pool(ThreadPoolBuilder::new(1).panic_switch(MODE).aging(AGING));
+
+        let (order_s, order_r) = channel();
+        let order = |priority: Priority| {
+            let order_s = order_s.clone();
+            move || order_s.send(priority).unwrap()
+        };
+        pool.execute_with_priority(Priority::Low, order(Priority::Low));
+        for _ in 0..2 * AGING {
+            pool.execute_with_priority(Priority::High, order(Priority::High));
+        }
+        pool.execute_with_priority(Priority::Normal, order(Priority::Normal));
+        assert_eq!(2 * AGING + 2, pool.queued_len());
+
+        drop(gate_s);
+        pool.join();
+        drop(order_s);
+
+        let ran: Vec<Priority> = order_r.iter().collect();
+        let mut expected = vec![Priority::High; AGING];
+        // both lower priorities have been passed over equally often
+        expected.push(Priority::Low);
+        expected.push(Priority::Normal);
+        expected.extend(vec![Priority::High; AGING]);
+        assert_eq!(expected, ran);
+    }
+
+    #[test]
+    fn test_threadpool_execute_after() {
+        const DELAY: Duration = Duration::from_millis(50);
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let (order_s, order_r) = channel();
+        let order = |n: usize| {
+            let order_s = order_s.clone();
+            move || order_s.send(n).unwrap()
+        };
+        let start = Instant::now();
+        pool.execute_after(2 * DELAY, order(2));
+        pool.execute_at(start + DELAY, order(1));
+        let cancelled = pool.execute_after(DELAY, order(0));
+        pool.execute(order(0));
+
+        assert!(cancelled.is_pending());
+        assert!(cancelled.cancel());
+        assert!(!cancelled.is_pending());
+        assert!(!cancelled.cancel());
+
+        assert_eq!(0, order_r.recv().unwrap());
+        assert_eq!(1, order_r.recv().unwrap());
+        assert!(start.elapsed() >= DELAY);
+        assert_eq!(2, order_r.recv().unwrap());
+        assert!(start.elapsed() >= 2 * DELAY);
+        assert!(order_r.recv_timeout(DELAY).is_err());
+
+        let shut_down = |timer_shutdown: TimerShutdown| {
+            let pool = ThreadPoolBuilder::new(SIZE)
+                .panic_switch(MODE)
+                .timer_shutdown(timer_shutdown)
+                .build()
+                .unwrap();
+            let timer = pool.execute_after(DELAY, || ());
+            let report = pool.shutdown(ShutdownMode::Graceful);
+            assert!(!timer.is_pending());
+            (report.completed, report.discarded.len())
+        };
+
+        assert_eq!((0, 1), shut_down(TimerShutdown::Discard));
+        assert_eq!((1, 0), shut_down(TimerShutdown::Fire));
+        let start = Instant::now();
+        assert_eq!((1, 0), shut_down(TimerShutdown::Wait));
+        assert!(start.elapsed() >= DELAY);
+
+        // waiting for delayed jobs does not keep a deadline from discarding them
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(MODE)
+            .timer_shutdown(TimerShutdown::Wait)
+            .build()
+            .unwrap();
+        pool.execute_after(Duration::from_secs(60), || ());
+        let start = Instant::now();
+        let report = pool.shutdown(ShutdownMode::Deadline(DELAY));
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert_eq!((0, 1), (report.completed, report.discarded.len()));
+    }
+
+    #[test]
+    fn test_threadpool_execute_recurring() {
+        const PERIOD: Duration = Duration::from_millis(20);
+
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let run = |cadence: Cadence, on_panic: RecurringPanic| {
+            let (tick_s, tick_r) = channel();
+            let mut n = 0;
+            let timer = pool.execute_recurring(PERIOD, cadence, on_panic, move || {
+                n += 1;
+                tick_s.send(n).unwrap();
+                if n == 2 {
+                    panic!("Oh no!");
                 }
+            });
+            (timer, tick_r)
+        };
+
+        let (timer, tick_r) = run(Cadence::FixedRate, RecurringPanic::Continue);
+        let start = Instant::now();
+        for n in 1..=4 {
+            assert_eq!(n, tick_r.recv().unwrap());
+        }
+        assert!(start.elapsed() >= 3 * PERIOD);
+        assert!(timer.is_pending());
+        assert!(timer.cancel());
+        assert!(tick_r.recv_timeout(4 * PERIOD).is_err());
+
+        let (timer, tick_r) = run(Cadence::FixedDelay, RecurringPanic::Stop);
+        assert_eq!(1, tick_r.recv().unwrap());
+        assert_eq!(2, tick_r.recv().unwrap());
+        assert!(tick_r.recv_timeout(4 * PERIOD).is_err());
+        assert!(!timer.is_pending());
+        assert!(!timer.cancel());
+
+        let report = pool.shutdown(ShutdownMode::Graceful);
+        assert_eq!(2, report.panicked);
+    }
+
+    #[test]
+    fn test_threadpool_execute_cancellable() {
+        let (pool, gate_s) = blocked_pool(
+            ThreadPoolBuilder::new(1)
+                .panic_switch(MODE)
+                .queue_capacity(1)
+                .rejection_policy(RejectionPolicy::Reject),
+        );
+        let queue_full = |pool: &ThreadPool| match pool.try_execute(|| ()) {
+            Err(e) => matches!(e.error(), Error::QueueFull),
+            Ok(()) => false,
+        };
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let flag_ref = Arc::clone(&flag);
+        let queued = pool.execute_cancellable(move |_| flag_ref.store(true, Ordering::SeqCst));
+        assert_eq!(1, pool.queued_len());
+        assert!(queue_full(&pool));
+
+        assert_eq!(JobStatus::Queued, queued.status());
+        assert!(queued.cancel());
+        assert!(!queued.cancel());
+        // the job is dropped right away
+        assert_eq!(1, Arc::strong_count(&flag));
+        // the stub left in the queue is not counted and gives up its slot
+        assert_eq!(0, pool.queued_len());
+        let (done_s, done_r) = bounded(1);
+        assert!(pool.try_execute(move || done_s.send(()).unwrap()).is_ok());
+        assert_eq!(1, pool.queued_len());
+        assert!(queue_full(&pool));
+
+        drop(gate_s);
+        done_r.recv().unwrap();
+        pool.join();
+        assert_eq!(JobStatus::CancelledBeforeStart, queued.wait());
+        assert!(!flag.load(Ordering::SeqCst));
+        // the stub is dropped without being run
+        assert_eq!(2, pool.stats().completed_jobs);
+        assert_eq!(0, pool.queued_len());
+
+        let done = pool.execute_cancellable(|_| ());
+        assert_eq!(JobStatus::Completed, done.wait());
+
+        let running = pool.execute_cancellable(|token| {
+            while !token.is_cancelled() {
+                std::thread::yield_now();
             }
+        });
+        while running.status() != JobStatus::Running {
+            std::thread::yield_now();
+        }
+        assert!(running.cancel());
+        assert_eq!(JobStatus::CancelledWhileRunning, running.wait());
 
-            // destruct all remaining worker-threads
-            while number_of_workers != 0 {
-                match statuses_r.recv().unwrap() {
-                    Status::Idle(id) => {
-                        workers[id].instructions_s.send(Message::Terminate).unwrap();
-                        thread::join(&mut workers[id].thread);
-                    }
-                    Status::Panic(id) => {
-                        thread::join(&mut workers[id].thread);
-                        if let PanicSwitch::Kill = mode {
-                            panicked_jobs += 1;
-                        };
-                    }
-                };
-                number_of_workers -= 1;
+        let panicking = pool.execute_cancellable(|_| panic!("Oh no!"));
+        assert_eq!(JobStatus::Panicked, panicking.wait());
+        assert!(!panicking.cancel());
+    }
+
+    #[test]
+    fn test_threadpool_execute_with_context() {
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let (ctx_s, ctx_r) = channel();
+        let report = |ctx_s: Sender<_>| {
+            move |ctx: &JobContext| {
+                let name = std::thread::current().name().map(String::from);
+                ctx_s
+                    .send((ctx.worker_id(), name, ctx.job_id(), ctx.deadline()))
+                    .unwrap();
             }
+        };
+
+        let deadline = Instant::now() + Duration::from_secs(60);
+        let ctx_ref = ctx_s.clone();
+        pool.execute_with_context(move |ctx| {
+            report(ctx_ref.clone())(ctx);
+            ctx.pool().execute_with_context(report(ctx_ref));
+        });
+        pool.execute_with_deadline(deadline, report(ctx_s));
+
+        let mut reports: Vec<_> = ctx_r.iter().take(3).collect();
+        reports.sort_by_key(|(_, _, job, _)| *job);
+
+        let job_ids: Vec<u64> = reports.iter().map(|(_, _, job, _)| *job).collect();
+        assert_eq!(vec![0, 1, 2], job_ids);
+        assert_eq!(
+            vec![None, Some(deadline), None],
+            reports
+                .iter()
+                .map(|(_, _, _, deadline)| *deadline)
+                .collect::<Vec<_>>()
+        );
+        for (worker, name, _, _) in reports {
+            let worker = worker.unwrap();
+            assert!(worker < SIZE);
+            assert_eq!(Some(format!("poolio-worker-{}", worker)), name);
+        }
 
-            if panicked_jobs > 0 {
-                eprintln!("Aborting process: {} panicked jobs.", panicked_jobs);
-                std::process::abort();
+        let handle = pool.execute_with_context(|ctx| {
+            while !ctx.is_cancelled() {
+                std::thread::yield_now();
             }
+            assert!(!ctx.is_overdue());
+        });
+        while handle.status() != JobStatus::Running {
+            std::thread::yield_now();
+        }
+        assert!(handle.cancel());
+        assert_eq!(JobStatus::CancelledWhileRunning, handle.wait());
+
+        let pool_handle = pool.handle();
+        drop(pool);
+        assert!(matches!(
+            pool_handle.try_execute(|| ()).unwrap_err().error(),
+            Error::PoolShutDown
+        ));
+    }
+
+    #[test]
+    fn test_threadpool_stats() {
+        let (pool, gate_s) = blocked_pool(ThreadPoolBuilder::new(SIZE).panic_switch(MODE));
+        assert_eq!(SIZE, pool.stats().configured_workers);
+        assert_eq!(SIZE, pool.stats().live_workers);
+
+        pool.execute(|| panic!("Oh no!"));
+        while pool.stats().respawns == 0 || pool.stats().running_jobs == 0 {
+            std::thread::yield_now();
+        }
+
+        let stats = pool.stats();
+        assert_eq!(1, stats.running_jobs);
+        assert_eq!(1, stats.panicked_jobs);
+        assert_eq!(0, stats.completed_jobs);
+        assert_eq!(0, stats.queued_jobs);
+
+        drop(gate_s);
+        pool.join();
+        let stats = pool.stats();
+        assert_eq!(
+            PoolStats {
+                configured_workers: SIZE,
+                live_workers: SIZE,
+                idle_workers: SIZE,
+                queued_jobs: 0,
+                running_jobs: 0,
+                completed_jobs: 1,
+                panicked_jobs: 1,
+                evicted_jobs: 0,
+                respawns: 1,
+                escalated: false,
+            },
+            stats
+        );
+
+        // due delayed jobs held back by the supervisor are queued as well
+        let (pool, gate_s) = blocked_pool(ThreadPoolBuilder::new(1).panic_switch(MODE));
+        pool.execute_after(Duration::ZERO, || ());
+        while pool.stats().queued_jobs == 0 {
+            std::thread::yield_now();
+        }
+        assert_eq!(1, pool.queued_len());
+        drop(gate_s);
+        pool.join();
+        assert_eq!(0, pool.queued_len());
+    }
+
+    #[test]
+    fn test_threadpool_latencies() {
+        const RUN_TIME: Duration = Duration::from_millis(10);
 
-            // ensure that `orders_r` lives as long as the thread to prevent reachability-errors
-            drop(orders_r);
+        let pool = ThreadPoolBuilder::new(1)
+            .panic_switch(MODE)
+            .latency_metrics(true)
+            .build()
+            .unwrap();
+        pool.execute(|| std::thread::sleep(RUN_TIME));
+        pool.execute(|| std::thread::sleep(RUN_TIME));
+        pool.execute(|| panic!("Oh no!"));
+        pool.join();
+
+        let latencies = pool.latencies().unwrap();
+        assert_eq!(3, latencies.queue_wait.count());
+        assert_eq!(3, latencies.run_time.count());
+        // the second job waited for the first one
+        assert!(latencies.queue_wait.max().unwrap() >= RUN_TIME);
+        assert!(latencies.run_time.p50().unwrap() >= RUN_TIME);
+
+        pool.reset_latencies();
+        assert_eq!(Some(Latencies::default()), pool.latencies());
+
+        // delayed and recurring jobs wait for a worker from when they are due on
+        const DELAY: Duration = Duration::from_millis(50);
+        let (tick_s, tick_r) = channel();
+        pool.execute_after(DELAY, || ());
+        let timer = pool.execute_every(DELAY, move || {
+            let _ = tick_s.send(());
         });
+        for _ in 0..2 {
+            tick_r.recv().unwrap();
+        }
+        timer.cancel();
+        pool.join();
+        let latencies = pool.latencies().unwrap();
+        assert!(latencies.run_time.count() >= 3);
+        assert!(latencies.queue_wait.max().unwrap() < DELAY);
 
-        Self { orders_s, thread }
+        // latencies are not recorded by default
+        let pool = ThreadPool::new(1, MODE).unwrap();
+        pool.execute(|| ());
+        pool.join();
+        assert_eq!(None, pool.latencies());
     }
-}
 
-/// [`Worker`] abstracts workers.
-struct Worker {
-    /// place to put instructions
-    instructions_s: Sender<Message>,
-    /// handle to join
-    thread: JoinHandle,
-}
+    #[test]
+    fn test_threadpool_observer() {
+        /// Counts the events it is told about.
+        #[derive(Default)]
+        struct Counter {
+            events: [AtomicUsize; 8],
+        }
 
-impl Worker {
-    /// Sets up a new worker.
-    /// - `id` is the worker's staff number.
-    /// - `statuses_s` is where the worker puts its current status.
-    ///
-    /// In particular, it spawns a thread and sets up a way to communicate to the thread.
-    fn new(id: StaffNumber, statuses_s: Sender<Status>) -> Self {
-        // this channel is used by the supervisor to contact this worker
-        let (instructions_s, instructions_r) = channel();
+        impl PoolObserver for Arc<Counter> {
+            fn job_submitted(&self) {
+                self.events[0].fetch_add(1, Ordering::SeqCst);
+            }
+            fn job_started(&self, worker: StaffNumber) {
+                assert!(worker < SIZE);
+                self.events[1].fetch_add(1, Ordering::SeqCst);
+            }
+            fn job_finished(&self, _worker: StaffNumber, _duration: Duration) {
+                self.events[2].fetch_add(1, Ordering::SeqCst);
+            }
+            fn job_panicked(&self, _worker: StaffNumber, payload: &(dyn Any + Send)) {
+                assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+                self.events[3].fetch_add(1, Ordering::SeqCst);
+            }
+            fn worker_spawned(&self, _worker: StaffNumber) {
+                self.events[4].fetch_add(1, Ordering::SeqCst);
+            }
+            fn worker_respawned(&self, _worker: StaffNumber) {
+                self.events[5].fetch_add(1, Ordering::SeqCst);
+            }
+            fn worker_dismissed(&self, _worker: StaffNumber) {
+                self.events[6].fetch_add(1, Ordering::SeqCst);
+            }
+            fn pool_terminated(&self, stats: &PoolStats) {
+                assert_eq!(2, stats.completed_jobs);
+                assert_eq!(1, stats.panicked_jobs);
+                self.events[7].fetch_add(1, Ordering::SeqCst);
+                panic!("Observers may panic.");
+            }
+        }
 
-        let thread = thread::spawn(move || {
-            // report for duty
-            statuses_s.send(Status::Idle(id)).unwrap();
+        let counter = Arc::new(Counter::default());
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(MODE)
+            .observer(Arc::clone(&counter))
+            .timer_shutdown(TimerShutdown::Fire)
+            .build()
+            .unwrap();
 
-            // keepin' running to execute jobs
-            loop {
-                let message = instructions_r.recv().unwrap();
+        pool.execute(|| ());
+        pool.execute(|| panic!("Oh no!"));
+        // a delayed job is accepted once it is due, that is, on shutdown here
+        pool.execute_after(Duration::from_secs(60), || ());
+        pool.shutdown(ShutdownMode::Graceful);
 
-                match message {
-                    Message::NewJob(job) => match std::panic::catch_unwind(job) {
-                        Ok(_) => {
-                            statuses_s.send(Status::Idle(id)).unwrap();
-                        }
-                        Err(_) => {
-                            statuses_s.send(Status::Panic(id)).unwrap();
-                            break;
-                        }
-                    },
-                    Message::Terminate => break,
+        let events: Vec<usize> = counter
+            .events
+            .iter()
+            .map(|count| count.load(Ordering::SeqCst))
+            .collect();
+        assert_eq!(vec![3, 3, 2, 1, SIZE, 1, SIZE, 1], events);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_threadpool_tracing() {
+        use tracing::span::Id;
+        use tracing_subscriber::layer::{Context, SubscriberExt};
+        use tracing_subscriber::registry::LookupSpan;
+
+        /// Counts how often the span of the submitter is entered.
+        struct Counter(Arc<AtomicUsize>);
+
+        impl<S> tracing_subscriber::Layer<S> for Counter
+        where
+            S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+        {
+            fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+                if ctx.span(id).is_some_and(|span| span.name() == "request") {
+                    self.0.fetch_add(1, Ordering::SeqCst);
                 }
             }
+        }
+
+        let entered = Arc::new(AtomicUsize::new(0));
+        let subscriber = tracing_subscriber::registry().with(Counter(Arc::clone(&entered)));
+        tracing::subscriber::with_default(subscriber, || {
+            let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+            let request = tracing::info_span!("request");
+            request.in_scope(|| {
+                pool.execute(|| ());
+                pool.execute(|| ());
+            });
+            pool.join();
+
+            // delayed and recurring jobs continue the span as well
+            let (tick_s, tick_r) = channel();
+            let timer = request.in_scope(|| {
+                pool.execute_after(Duration::ZERO, || ());
+                pool.execute_every(Duration::from_millis(1), move || {
+                    let _ = tick_s.send(());
+                })
+            });
+            for _ in 0..2 {
+                tick_r.recv().unwrap();
+            }
+            timer.cancel();
+            pool.shutdown(ShutdownMode::Graceful);
         });
 
-        Self {
-            instructions_s,
-            thread,
-        }
+        // the span is entered by the submitter twice, once for each job and at least twice for the recurring job
+        assert!(entered.load(Ordering::SeqCst) >= 7);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-    use std::sync::Arc;
+    #[test]
+    fn test_threadpool_propagate() {
+        let pool = ThreadPool::new(SIZE, PanicSwitch::Propagate).unwrap();
+        pool.execute(|| panic!("Oh no!"));
 
-    // settings
-    const SIZE: usize = 2; //= 6; && = 12; && = 36;
-    const MODE: PanicSwitch = PanicSwitch::Respawn; //= PanicSwitch::Kill;
-    const ID: StaffNumber = 0;
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| pool.join())).unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+        // the panic is only re-raised once
+        pool.join();
 
-    #[test]
-    fn test_threadpool_new_ok() {
-        let pool = ThreadPool::new(SIZE, MODE);
-        assert!(matches!(pool, Ok(_)));
+        // no more jobs are accepted
+        let rejected = pool.try_execute(|| ()).unwrap_err();
+        assert!(matches!(rejected.error(), Error::PoolShutDown));
+
+        let report = pool.shutdown(ShutdownMode::Graceful);
+        assert_eq!(1, report.panicked);
+
+        let pool = ThreadPool::new(1, PanicSwitch::Propagate).unwrap();
+        let (job, started_r, gate_s) = gated_panic();
+        pool.execute(job);
+        started_r.recv().unwrap();
+        pool.execute(|| ());
+        pool.execute(|| ());
+
+        // the queued jobs are discarded and the panic is re-raised by the shutdown
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            drop(gate_s);
+            pool.shutdown(ShutdownMode::Graceful);
+        }))
+        .unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+
+        // the discarded jobs are dropped rather than kept for the report
+        let pool = ThreadPool::new(1, PanicSwitch::Propagate).unwrap();
+        let (job, started_r, gate_s) = gated_panic();
+        pool.execute(job);
+        started_r.recv().unwrap();
+        let handle = pool.spawn(|| 42);
+        pool.execute_after(Duration::from_secs(60), || ());
+        drop(gate_s);
+        assert!(std::panic::catch_unwind(AssertUnwindSafe(|| pool.join())).is_err());
+        assert!(handle.join().is_err());
+        let report = pool.shutdown(ShutdownMode::Graceful);
+        assert_eq!(1, report.panicked);
+        assert!(report.discarded.is_empty());
+
+        let pool = ThreadPool::new(1, PanicSwitch::Propagate).unwrap();
+        pool.execute(|| panic!("Oh no!"));
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| drop(pool))).unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
     }
 
     #[test]
-    fn test_threadpool_new_err() {
-        let pool = ThreadPool::new(0, MODE);
-        assert!(matches!(pool, Err(_)));
+    fn test_threadpool_scope_propagate() {
+        /// Counts how often it has been dropped.
+        struct Guard<'a>(&'a AtomicUsize);
+
+        impl Drop for Guard<'_> {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let pool = ThreadPool::new(1, PanicSwitch::Propagate).unwrap();
+        let ran = AtomicUsize::new(0);
+        let dropped = AtomicUsize::new(0);
+
+        // the queued scoped jobs are discarded which must not keep the scope waiting
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            pool.scope(|s| {
+                let (job, started_r, gate_s) = gated_panic();
+                s.execute(job);
+                started_r.recv().unwrap();
+                for _ in 0..2 {
+                    let guard = Guard(&dropped);
+                    s.execute(|| {
+                        let _guard = guard;
+                        ran.fetch_add(1, Ordering::SeqCst);
+                    });
+                }
+                drop(gate_s);
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(0, ran.load(Ordering::SeqCst));
+        // the discarded jobs have been dropped before the scope ended
+        assert_eq!(2, dropped.load(Ordering::SeqCst));
+
+        // a job slipping into the queue after the jobs have been cleared out is cleared out as well
+        let (late_s, late_r) = bounded::<()>(1);
+        let late: Box<dyn Task> = Box::new(move || drop(late_s));
+        pool.supervisor.inlet.jobs_s[Priority::Normal as usize]
+            .send(late)
+            .unwrap();
+        assert_eq!(
+            Err(RecvTimeoutError::Disconnected),
+            late_r.recv_timeout(Duration::from_secs(10))
+        );
+
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| pool.join())).unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
     }
 
     #[test]
-    fn test_threadpool_execute() {
-        const N: usize = 5;
+    fn test_threadpool_panic_policy() {
+        /// Retires the first worker with a panicked job and closes the pool on the second.
+        struct Policy(usize);
 
-        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+        impl PanicPolicy for Policy {
+            fn on_panic(
+                &mut self,
+                worker: StaffNumber,
+                payload: &(dyn Any + Send),
+            ) -> PanicDecision {
+                assert!(worker < SIZE);
+                assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+                self.0 += 1;
+                match self.0 {
+                    1 => PanicDecision::Retire,
+                    _ => PanicDecision::Close,
+                }
+            }
+        }
+
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_policy(Policy(0))
+            .build()
+            .unwrap();
+
+        pool.execute(|| panic!("Oh no!"));
+        pool.join();
+        assert_eq!(SIZE - 1, pool.size());
+        assert_eq!(SIZE - 1, pool.stats().configured_workers);
 
         let counter = Arc::new(AtomicUsize::new(0));
+        let (job, started_r, gate_s) = gated_panic();
+        pool.execute(job);
+        started_r.recv().unwrap();
+        for _ in 0..SIZE {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(gate_s);
+        pool.join();
 
-        let count_to = |n: usize| {
-            for _ in 0..n {
-                let counter = Arc::clone(&counter);
-                pool.execute(move || {
-                    counter.fetch_add(1, Ordering::SeqCst);
-                });
-            }
-        };
+        // the pool does not accept jobs anymore, finishes the remaining ones and dismisses its workers without respawning any
+        assert!(pool.try_execute(|| ()).is_err());
+        assert_eq!(SIZE, counter.load(Ordering::SeqCst));
+        assert_eq!(0, pool.size());
+        assert_eq!(0, pool.stats().respawns);
+        assert!(matches!(pool.resize(SIZE), Err(Error::PoolShutDown)));
+        assert_eq!(2, pool.shutdown(ShutdownMode::Graceful).panicked);
 
-        for _ in 0..N {
-            count_to(SIZE);
-            if let PanicSwitch::Respawn = MODE {
-                pool.execute(|| panic!("Oh no!"));
+        // the last worker is not retired but the pool is closed instead
+        let pool = ThreadPoolBuilder::new(1)
+            .panic_policy(Policy(0))
+            .build()
+            .unwrap();
+        let counter = Arc::new(AtomicUsize::new(0));
+        let (job, started_r, gate_s) = gated_panic();
+        pool.execute(job);
+        started_r.recv().unwrap();
+        let counter_ref = Arc::clone(&counter);
+        pool.execute(move || {
+            counter_ref.fetch_add(1, Ordering::SeqCst);
+        });
+        drop(gate_s);
+        pool.join();
+
+        assert!(pool.try_execute(|| ()).is_err());
+        assert_eq!(1, counter.load(Ordering::SeqCst));
+        assert_eq!(0, pool.size());
+        assert_eq!(1, pool.shutdown(ShutdownMode::Graceful).panicked);
+
+        // retiring the only worker of an elastic pool lets a new one take over to keep the minimal size
+        let pool = ThreadPoolBuilder::new(1)
+            .max_size(SIZE + 1)
+            .panic_policy(Policy(0))
+            .build()
+            .unwrap();
+        pool.execute(|| panic!("Oh no!"));
+        pool.join();
+        assert_eq!(1, pool.size());
+        assert_eq!(1, pool.stats().configured_workers);
+
+        let (done_s, done_r) = bounded(1);
+        pool.execute(move || done_s.send(()).unwrap());
+        done_r.recv().unwrap();
+        assert_eq!(1, pool.shutdown(ShutdownMode::Graceful).panicked);
+    }
+
+    #[test]
+    fn test_threadpool_restart_intensity() {
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(PanicSwitch::Respawn)
+            .restart_intensity(2, Duration::from_secs(60), PanicDecision::Propagate)
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            pool.execute(|| panic!("Oh no!"));
+        }
+        pool.join();
+        let stats = pool.stats();
+        assert_eq!(2, stats.respawns);
+        assert!(!stats.escalated);
+
+        pool.execute(|| panic!("Oh no!"));
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| pool.join())).unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+        let stats = pool.stats();
+        assert_eq!(2, stats.respawns);
+        assert!(stats.escalated);
+
+        // respawns outside of the window do not count, that is, none if the window is empty
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_switch(PanicSwitch::Respawn)
+            .restart_intensity(1, Duration::ZERO, PanicDecision::Retire)
+            .build()
+            .unwrap();
+
+        for _ in 0..3 {
+            pool.execute(|| panic!("Oh no!"));
+            pool.join();
+        }
+        let stats = pool.stats();
+        assert_eq!(3, stats.respawns);
+        assert!(!stats.escalated);
+        assert_eq!(SIZE, pool.size());
+
+        // degrading a single worker closes the pool instead of leaving the remaining jobs without workers
+        let pool = ThreadPoolBuilder::new(1)
+            .panic_switch(PanicSwitch::Respawn)
+            .restart_intensity(0, Duration::from_secs(60), PanicDecision::Retire)
+            .build()
+            .unwrap();
+        let (job, started_r, gate_s) = gated_panic();
+        pool.execute(job);
+        started_r.recv().unwrap();
+        let finished = Arc::new(AtomicBool::new(false));
+        let finished_ref = Arc::clone(&finished);
+        pool.execute(move || finished_ref.store(true, Ordering::SeqCst));
+        drop(gate_s);
+        pool.join();
+        assert!(finished.load(Ordering::SeqCst));
+        assert!(pool.stats().escalated);
+        assert_eq!(0, pool.size());
+        assert!(pool.try_execute(|| ()).is_err());
+
+        // falling back must not respawn worker-threads
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .restart_intensity(1, Duration::from_secs(60), PanicDecision::Respawn)
+            .build();
+        assert!(matches!(pool, Err(Error::InvalidFallback)));
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .restart_intensity(1, Duration::from_secs(60), PanicDecision::Close)
+            .build();
+        assert!(pool.is_ok());
+    }
+
+    #[test]
+    fn test_threadpool_spawn_panic() {
+        /// Respawns the workers and records the payloads.
+        struct Policy(Sender<String>);
+
+        impl PanicPolicy for Policy {
+            fn on_panic(
+                &mut self,
+                _worker: StaffNumber,
+                payload: &(dyn Any + Send),
+            ) -> PanicDecision {
+                let message = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+                self.0.send(String::from(message)).unwrap();
+                PanicDecision::Respawn
             }
         }
 
-        drop(pool);
+        let (payloads_s, payloads_r) = channel();
+        let pool = ThreadPoolBuilder::new(SIZE)
+            .panic_policy(Policy(payloads_s))
+            .build()
+            .unwrap();
 
-        assert_eq!(N * SIZE, counter.load(Ordering::SeqCst));
+        // both the policy and the handle get the original payload
+        let handle = pool.spawn(|| -> usize { panic!("Oh no!") });
+        let payload = handle.join().unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+        assert_eq!("Oh no!", payloads_r.recv().unwrap());
+
+        let pool = ThreadPool::new(SIZE, PanicSwitch::Propagate).unwrap();
+        let handle = pool.spawn(|| -> usize { panic!("Oh no!") });
+        assert!(handle.join().is_err());
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| pool.join())).unwrap_err();
+        assert_eq!(Some(&"Oh no!"), payload.downcast_ref::<&str>());
+    }
+
+    #[test]
+    fn test_threadpool_spawn() {
+        let pool = ThreadPool::new(SIZE, MODE).unwrap();
+
+        let handles: Vec<JobHandle<usize>> = (0..SIZE).map(|n| pool.spawn(move || n * n)).collect();
+        for (n, handle) in handles.into_iter().enumerate() {
+            assert_eq!(n * n, handle.join().unwrap());
+        }
+
+        if let PanicSwitch::Respawn = MODE {
+            let handle = pool.spawn(|| -> usize { panic!("Oh no!") });
+            assert!(handle.join().is_err());
+            assert_eq!(1, pool.spawn(|| 1).join().unwrap());
+        }
     }
 
     #[test]
     fn test_worker_thread_newjob() {
         let (statuses_s, statuses_r) = channel();
-        let mut worker = Worker::new(ID, statuses_s);
+        let mut worker = Worker::new(ID, statuses_s, contract()).unwrap();
 
         assert!(matches!(statuses_r.recv().unwrap(), Status::Idle(ID)));
 
@@ -499,7 +5638,10 @@ mod tests {
 
         let job = Box::new(|| panic!("Oh no!"));
         worker.instructions_s.send(Message::NewJob(job)).unwrap();
-        assert!(matches!(statuses_r.recv().unwrap(), Status::Panic(ID)));
+        assert!(matches!(
+            statuses_r.recv().unwrap(),
+            Status::Panic(ID, _, None)
+        ));
 
         thread::join(&mut worker.thread);
     }
@@ -507,7 +5649,7 @@ mod tests {
     #[test]
     fn test_worker_thread_terminate() {
         let (statuses_s, statuses_r) = channel();
-        let mut worker = Worker::new(ID, statuses_s);
+        let mut worker = Worker::new(ID, statuses_s, contract()).unwrap();
 
         assert!(matches!(statuses_r.recv().unwrap(), Status::Idle(ID)));
 
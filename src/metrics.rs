@@ -0,0 +1,341 @@
+//! This module provides the latency metrics of a [`ThreadPool`](crate::ThreadPool).
+//!
+//! If enabled by [`ThreadPoolBuilder::latency_metrics`](crate::ThreadPoolBuilder::latency_metrics), the pool timestamps each job when it is ordered, when a worker starts it and when it is done.
+//! From these timestamps, it records how long jobs wait in the queue and how long they run in fixed-bucket [`Histogram`]s.
+//!
+//! # Examples
+//!
+//! Setting up a pool which records latencies and looking at how long its jobs ran:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! let pool = poolio::ThreadPoolBuilder::new(2)
+//!     .latency_metrics(true)
+//!     .build()
+//!     .unwrap();
+//! for _ in 0..10 {
+//!     pool.execute(|| std::thread::sleep(Duration::from_millis(1)));
+//! }
+//! pool.join();
+//!
+//! let latencies = pool.latencies().unwrap();
+//! assert_eq!(10, latencies.run_time.count());
+//! assert!(latencies.run_time.p50().unwrap() >= Duration::from_millis(1));
+//! ```
+
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// upper bounds of the buckets in nanoseconds (1-2-5 steps from a microsecond to 50 seconds)
+const BOUNDS: [u64; 24] = [
+    1_000,
+    2_000,
+    5_000,
+    10_000,
+    20_000,
+    50_000,
+    100_000,
+    200_000,
+    500_000,
+    1_000_000,
+    2_000_000,
+    5_000_000,
+    10_000_000,
+    20_000_000,
+    50_000_000,
+    100_000_000,
+    200_000_000,
+    500_000_000,
+    1_000_000_000,
+    2_000_000_000,
+    5_000_000_000,
+    10_000_000_000,
+    20_000_000_000,
+    50_000_000_000,
+];
+
+/// number of buckets (the last one catching everything beyond the bounds)
+const BUCKETS: usize = BOUNDS.len() + 1;
+
+/// Converts `duration` into nanoseconds (saturating).
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// [`Histogram`]s count durations in fixed buckets ranging from a microsecond to 50 seconds in 1-2-5 steps.
+///
+/// Recording is lock-free and thus cheap enough to be done for every job.
+#[derive(Debug, Default)]
+pub struct Histogram {
+    /// counts of the buckets
+    buckets: [AtomicU64; BUCKETS],
+    /// sum of the recorded durations in nanoseconds
+    sum: AtomicU64,
+    /// longest recorded duration in nanoseconds
+    max: AtomicU64,
+}
+
+impl Histogram {
+    /// Sets up an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts a duration.
+    /// - `duration` is the duration to be counted.
+    pub fn record(&self, duration: Duration) {
+        let nanos = nanos(duration);
+        let bucket = BOUNDS.partition_point(|bound| *bound < nanos);
+
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(nanos, Ordering::Relaxed);
+        self.max.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counts.
+    pub fn snapshot(&self) -> HistogramSnapshot {
+        HistogramSnapshot {
+            buckets: std::array::from_fn(|bucket| self.buckets[bucket].load(Ordering::Relaxed)),
+            sum: self.sum.load(Ordering::Relaxed),
+            max: self.max.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Forgets all counted durations.
+    pub fn reset(&self) {
+        for bucket in &self.buckets {
+            bucket.store(0, Ordering::Relaxed);
+        }
+        self.sum.store(0, Ordering::Relaxed);
+        self.max.store(0, Ordering::Relaxed);
+    }
+}
+
+/// [`HistogramSnapshot`]s are the counts of a [`Histogram`] at some point in time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HistogramSnapshot {
+    /// counts of the buckets
+    buckets: [u64; BUCKETS],
+    /// sum of the counted durations in nanoseconds
+    sum: u64,
+    /// longest counted duration in nanoseconds
+    max: u64,
+}
+
+impl HistogramSnapshot {
+    /// Tells how many durations have been counted.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// Tells the sum of the counted durations.
+    pub fn sum(&self) -> Duration {
+        Duration::from_nanos(self.sum)
+    }
+
+    /// Tells the mean of the counted durations (if there are any).
+    pub fn mean(&self) -> Option<Duration> {
+        match self.count() {
+            0 => None,
+            count => Some(Duration::from_nanos(self.sum / count)),
+        }
+    }
+
+    /// Tells the longest counted duration (if there is any).
+    pub fn max(&self) -> Option<Duration> {
+        (self.count() > 0).then(|| Duration::from_nanos(self.max))
+    }
+
+    /// Estimates a quantile of the counted durations (if there are any).
+    /// - `quantile` is the quantile between 0 and 1.
+    ///
+    /// The estimate is the upper bound of the bucket containing the quantile but never more than the longest counted duration.
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if `quantile` is not between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// Estimating the median of some durations:
+    ///
+    /// ```
+    /// use poolio::metrics::Histogram;
+    /// use std::time::Duration;
+    ///
+    /// let histogram = Histogram::new();
+    /// for millis in [3, 4, 30] {
+    ///     histogram.record(Duration::from_millis(millis));
+    /// }
+    /// assert_eq!(Some(Duration::from_millis(5)), histogram.snapshot().percentile(0.5));
+    /// ```
+    pub fn percentile(&self, quantile: f64) -> Option<Duration> {
+        assert!(
+            (0.0..=1.0).contains(&quantile),
+            "Quantiles have to be between 0 and 1."
+        );
+
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        // the rank of the quantile among the counted durations (starting from 1)
+        let rank = ((quantile * count as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                let bound = BOUNDS.get(bucket).copied().unwrap_or(u64::MAX);
+                return Some(Duration::from_nanos(bound.min(self.max)));
+            }
+        }
+
+        unreachable!("The rank of a quantile does not exceed the count.")
+    }
+
+    /// Estimates the median of the counted durations (see [`HistogramSnapshot::percentile`]).
+    pub fn p50(&self) -> Option<Duration> {
+        self.percentile(0.5)
+    }
+
+    /// Estimates the 95th percentile of the counted durations (see [`HistogramSnapshot::percentile`]).
+    pub fn p95(&self) -> Option<Duration> {
+        self.percentile(0.95)
+    }
+
+    /// Estimates the 99th percentile of the counted durations (see [`HistogramSnapshot::percentile`]).
+    pub fn p99(&self) -> Option<Duration> {
+        self.percentile(0.99)
+    }
+
+    /// Lists the buckets as pairs of their upper bound ([`None`] for the last one) and their count.
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        let bounds = BOUNDS
+            .iter()
+            .map(|bound| Some(Duration::from_nanos(*bound)));
+        bounds
+            .chain(std::iter::once(None))
+            .zip(self.buckets.iter().copied())
+    }
+}
+
+/// [`Latencies`] are snapshots of the latency metrics of a [`ThreadPool`](crate::ThreadPool) (see [`ThreadPool::latencies`](crate::ThreadPool::latencies)).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Latencies {
+    /// how long jobs waited in the queue before a worker started them
+    pub queue_wait: HistogramSnapshot,
+    /// how long jobs ran (including those which panicked)
+    pub run_time: HistogramSnapshot,
+}
+
+/// [`JobTimes`] is where a pool records the latencies of its jobs.
+#[derive(Debug, Default)]
+pub(crate) struct JobTimes {
+    /// how long jobs waited in the queue
+    queue_wait: Histogram,
+    /// how long jobs ran
+    run_time: Histogram,
+}
+
+impl JobTimes {
+    /// Records that a job ordered at `ordered` has been started now and hands out the time it has been started at.
+    pub(crate) fn start(&self, ordered: Instant) -> Instant {
+        let started = Instant::now();
+        self.queue_wait.record(started - ordered);
+        started
+    }
+
+    /// Records that a job started at `started` is done now.
+    pub(crate) fn finish(&self, started: Instant) {
+        self.run_time.record(started.elapsed());
+    }
+
+    /// Takes a snapshot of the latencies.
+    pub(crate) fn snapshot(&self) -> Latencies {
+        Latencies {
+            queue_wait: self.queue_wait.snapshot(),
+            run_time: self.run_time.snapshot(),
+        }
+    }
+
+    /// Forgets all recorded latencies.
+    pub(crate) fn reset(&self) {
+        self.queue_wait.reset();
+        self.run_time.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_record() {
+        let histogram = Histogram::new();
+        histogram.record(Duration::ZERO);
+        histogram.record(Duration::from_micros(1));
+        histogram.record(Duration::from_micros(3));
+        histogram.record(Duration::from_secs(3600));
+
+        let snapshot = histogram.snapshot();
+        let counts: Vec<u64> = snapshot.buckets().map(|(_, count)| count).collect();
+        assert_eq!(BUCKETS, counts.len());
+        assert_eq!(2, counts[0]);
+        assert_eq!(1, counts[2]);
+        assert_eq!(1, counts[BUCKETS - 1]);
+        assert_eq!(4, snapshot.count());
+        assert_eq!(Some(Duration::from_secs(3600)), snapshot.max());
+        assert_eq!(
+            Duration::from_secs(3600) + Duration::from_micros(4),
+            snapshot.sum()
+        );
+
+        histogram.reset();
+        assert_eq!(HistogramSnapshot::default(), histogram.snapshot());
+    }
+
+    #[test]
+    fn test_histogram_percentile() {
+        let histogram = Histogram::new();
+        assert_eq!(None, histogram.snapshot().p50());
+        assert_eq!(None, histogram.snapshot().mean());
+
+        for _ in 0..94 {
+            histogram.record(Duration::from_micros(15));
+        }
+        for _ in 0..4 {
+            histogram.record(Duration::from_millis(15));
+        }
+        for _ in 0..2 {
+            histogram.record(Duration::from_secs(100));
+        }
+
+        let snapshot = histogram.snapshot();
+        assert_eq!(Some(Duration::from_micros(20)), snapshot.p50());
+        assert_eq!(Some(Duration::from_millis(20)), snapshot.p95());
+        // beyond the last bound the longest duration is the estimate
+        assert_eq!(Some(Duration::from_secs(100)), snapshot.p99());
+        assert_eq!(Some(Duration::from_micros(20)), snapshot.percentile(0.0));
+        assert_eq!(Some(Duration::from_secs(100)), snapshot.percentile(1.0));
+    }
+
+    #[test]
+    fn test_jobtimes() {
+        let times = JobTimes::default();
+        let ordered = Instant::now();
+        let started = times.start(ordered);
+        times.finish(started);
+
+        let latencies = times.snapshot();
+        assert_eq!(1, latencies.queue_wait.count());
+        assert_eq!(1, latencies.run_time.count());
+
+        times.reset();
+        assert_eq!(Latencies::default(), times.snapshot());
+    }
+}
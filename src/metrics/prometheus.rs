@@ -0,0 +1,484 @@
+//! This module renders the metrics of [`ThreadPool`](crate::ThreadPool)s in the [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/).
+//!
+//! It is only available with the `prometheus` feature.
+//! The figures of [`ThreadPool::stats`](crate::ThreadPool::stats) are rendered as gauges and counters and the latency metrics of [`ThreadPool::latencies`](crate::ThreadPool::latencies) as histograms (in seconds) if the pool records them.
+//! All samples are labelled with the name chosen for the pool.
+//!
+//! # Examples
+//!
+//! Setting up a pool which records latencies and serving its metrics on a Prometheus endpoint:
+//!
+//! ```
+//! use poolio::metrics::prometheus::Exporter;
+//! use std::net::TcpListener;
+//!
+//! let pool = poolio::ThreadPoolBuilder::new(2)
+//!     .latency_metrics(true)
+//!     .build()
+//!     .unwrap();
+//! let exporter = Exporter::new().register("requests", pool.handle());
+//!
+//! let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+//! std::thread::spawn(move || exporter.serve(listener));
+//! ```
+
+use crate::metrics::{HistogramSnapshot, Latencies};
+use crate::{PoolHandle, PoolStats};
+
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+/// how long a client may take to send its request and to receive the response all together (by default)
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// maximal number of bytes read from a request (including its headers)
+const MAX_REQUEST: u64 = 8 * 1024;
+
+/// Types the samples of a metric family: the name of the pool and the figures of the pool.
+type Sample<'a> = (&'a str, &'a PoolStats, Option<&'a Latencies>);
+
+/// Types the description of a gauge or counter: its name, its type, its help text and how to read it from [`PoolStats`].
+type Figure = (
+    &'static str,
+    &'static str,
+    &'static str,
+    fn(&PoolStats) -> usize,
+);
+
+/// Types the description of a histogram: its name, its help text and how to read it from [`Latencies`].
+type Histogram = (
+    &'static str,
+    &'static str,
+    fn(&Latencies) -> &HistogramSnapshot,
+);
+
+/// the gauges and counters rendered from [`PoolStats`]
+const FIGURES: [Figure; 10] = [
+    (
+        "poolio_workers_configured",
+        "gauge",
+        "Number of workers the pool is supposed to have.",
+        |stats| stats.configured_workers,
+    ),
+    (
+        "poolio_workers_live",
+        "gauge",
+        "Number of workers currently employed.",
+        |stats| stats.live_workers,
+    ),
+    (
+        "poolio_workers_idle",
+        "gauge",
+        "Number of workers currently waiting for a job.",
+        |stats| stats.idle_workers,
+    ),
+    (
+        "poolio_jobs_queued",
+        "gauge",
+        "Number of jobs waiting in the queue.",
+        |stats| stats.queued_jobs,
+    ),
+    (
+        "poolio_jobs_running",
+        "gauge",
+        "Number of jobs currently running.",
+        |stats| stats.running_jobs,
+    ),
+    (
+        "poolio_jobs_completed_total",
+        "counter",
+        "Number of jobs which have been completed.",
+        |stats| stats.completed_jobs,
+    ),
+    (
+        "poolio_jobs_panicked_total",
+        "counter",
+        "Number of jobs which have panicked.",
+        |stats| stats.panicked_jobs,
+    ),
+    (
+        "poolio_jobs_evicted_total",
+        "counter",
+        "Number of jobs which have been discarded to make room for others.",
+        |stats| stats.evicted_jobs,
+    ),
+    (
+        "poolio_workers_respawned_total",
+        "counter",
+        "Number of workers which have been respawned.",
+        |stats| stats.respawns,
+    ),
+    (
+        "poolio_escalated",
+        "gauge",
+        "Whether the pool has exceeded its restart intensity (1) or not (0).",
+        |stats| usize::from(stats.escalated),
+    ),
+];
+
+/// the histograms rendered from [`Latencies`]
+const HISTOGRAMS: [Histogram; 2] = [
+    (
+        "poolio_job_queue_wait_seconds",
+        "How long jobs waited in the queue.",
+        |latencies| &latencies.queue_wait,
+    ),
+    (
+        "poolio_job_run_time_seconds",
+        "How long jobs ran.",
+        |latencies| &latencies.run_time,
+    ),
+];
+
+/// Escapes `value` to be used as a label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
+
+/// Renders the metric families of some pools.
+/// - `samples` are the figures of the pools.
+fn render_samples(samples: &[Sample]) -> String {
+    let mut out = String::new();
+
+    for (name, kind, help, figure) in FIGURES {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for (pool, stats, _) in samples {
+            let _ = writeln!(
+                out,
+                "{}{{pool=\"{}\"}} {}",
+                name,
+                escape(pool),
+                figure(stats)
+            );
+        }
+    }
+
+    for (name, help, histogram) in HISTOGRAMS {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} histogram", name);
+        for (pool, _, latencies) in samples {
+            // pools without latency metrics have no samples
+            let Some(latencies) = latencies else {
+                continue;
+            };
+            let pool = escape(pool);
+            let snapshot = histogram(latencies);
+
+            // the buckets of Prometheus are cumulative
+            let mut count = 0;
+            for (bound, n) in snapshot.buckets() {
+                count += n;
+                let le = match bound {
+                    Some(bound) => bound.as_secs_f64().to_string(),
+                    None => String::from("+Inf"),
+                };
+                let _ = writeln!(
+                    out,
+                    "{}_bucket{{pool=\"{}\",le=\"{}\"}} {}",
+                    name, pool, le, count
+                );
+            }
+            let _ = writeln!(
+                out,
+                "{}_sum{{pool=\"{}\"}} {}",
+                name,
+                pool,
+                snapshot.sum().as_secs_f64()
+            );
+            let _ = writeln!(out, "{}_count{{pool=\"{}\"}} {}", name, pool, count);
+        }
+    }
+
+    out
+}
+
+/// Renders the metrics of a single pool.
+/// - `name` is the name the samples are labelled with.
+/// - `stats` are the figures of the pool.
+/// - `latencies` are the latency metrics of the pool (if it records them).
+///
+/// # Examples
+///
+/// Rendering the metrics of a pool:
+///
+/// ```
+/// use poolio::metrics::prometheus;
+///
+/// let pool = poolio::ThreadPool::new(2, poolio::PanicSwitch::Kill).unwrap();
+/// pool.execute(|| println!("done"));
+/// pool.join();
+///
+/// let text = prometheus::render("requests", &pool.stats(), pool.latencies().as_ref());
+/// assert!(text.contains("poolio_jobs_completed_total{pool=\"requests\"} 1\n"));
+/// ```
+pub fn render(name: &str, stats: &PoolStats, latencies: Option<&Latencies>) -> String {
+    render_samples(&[(name, stats, latencies)])
+}
+
+/// [`Exporter`]s render the metrics of the pools registered with them and may serve them over HTTP.
+#[derive(Clone, Debug)]
+pub struct Exporter {
+    /// the registered pools along with their names
+    pools: Vec<(String, PoolHandle)>,
+    /// how long a client may take to be served
+    timeout: Duration,
+}
+
+impl Default for Exporter {
+    fn default() -> Self {
+        Self {
+            pools: Vec::new(),
+            timeout: TIMEOUT,
+        }
+    }
+}
+
+impl Exporter {
+    /// Sets up an exporter without any pools.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a pool.
+    /// - `name` is the name the samples of the pool are labelled with.
+    /// - `pool` is a handle of the pool (see [`ThreadPool::handle`](crate::ThreadPool::handle)).
+    pub fn register(mut self, name: &str, pool: PoolHandle) -> Self {
+        self.pools.push((String::from(name), pool));
+        self
+    }
+
+    /// Sets how long a client may take to send its request and to receive the response all together (five seconds by default).
+    /// - `timeout` is the time the client has.
+    ///
+    /// Since the requests are answered one after the other, this is also how long a client can stall the requests coming in behind it.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Renders the current metrics of all registered pools.
+    pub fn render(&self) -> String {
+        let figures: Vec<(PoolStats, Option<Latencies>)> = self
+            .pools
+            .iter()
+            .map(|(_, pool)| (pool.stats(), pool.latencies()))
+            .collect();
+        let samples: Vec<Sample> = self
+            .pools
+            .iter()
+            .zip(&figures)
+            .map(|((name, _), (stats, latencies))| (name.as_str(), stats, latencies.as_ref()))
+            .collect();
+
+        render_samples(&samples)
+    }
+
+    /// Serves the metrics of all registered pools on `/metrics`.
+    /// - `listener` is where the requests come in.
+    ///
+    /// This method blocks and answers the requests one after the other.
+    /// Requests other than `GET /metrics` are answered with `404 NOT FOUND`.
+    /// Requests exceeding 8 KiB are answered with `431 REQUEST HEADER FIELDS TOO LARGE` and incomplete ones with `400 BAD REQUEST`.
+    /// A client which takes longer than the timeout to send its request and to receive the response is dropped (see [`Exporter::timeout`]), so it stalls the endpoint for that long at most.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a connection could not be accepted.
+    /// Failing to answer a request is not an error.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            // the client may have gone which is not a problem
+            let _ = self.answer(stream?);
+        }
+
+        Ok(())
+    }
+
+    /// Answers the request coming in on `stream`.
+    fn answer(&self, stream: TcpStream) -> io::Result<()> {
+        let mut connection = Connection {
+            stream,
+            deadline: Instant::now() + self.timeout,
+        };
+        let mut reader = BufReader::new((&mut connection).take(MAX_REQUEST));
+
+        let mut request = String::new();
+        reader.read_line(&mut request)?;
+
+        // the headers are of no interest but the request is only complete with the empty line ending them
+        let mut complete = request.ends_with('\n');
+        let mut header = String::new();
+        while complete && !matches!(header.as_str(), "\r\n" | "\n") {
+            header.clear();
+            reader.read_line(&mut header)?;
+            complete = header.ends_with('\n');
+        }
+        let exhausted = reader.get_ref().limit() == 0;
+        drop(reader);
+
+        let response = if !complete && exhausted {
+            String::from(
+                "HTTP/1.1 431 REQUEST HEADER FIELDS TOO LARGE\r\nContent-Length: 0\r\n\r\n",
+            )
+        } else if !complete {
+            String::from("HTTP/1.1 400 BAD REQUEST\r\nContent-Length: 0\r\n\r\n")
+        } else if request.starts_with("GET /metrics ") {
+            let body = self.render();
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+        } else {
+            String::from("HTTP/1.1 404 NOT FOUND\r\nContent-Length: 0\r\n\r\n")
+        };
+
+        connection.write_all(response.as_bytes())?;
+        connection.flush()
+    }
+}
+
+/// [`Connection`]s are the streams to the clients which fail to read or write beyond a deadline.
+struct Connection {
+    /// the stream to the client
+    stream: TcpStream,
+    /// when the client has to be answered
+    deadline: Instant,
+}
+
+impl Connection {
+    /// Tells how long the client may still take and fails if its time is up.
+    fn time_left(&self) -> io::Result<Duration> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        Ok(left)
+    }
+}
+
+impl Read for Connection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.set_read_timeout(Some(self.time_left()?))?;
+        self.stream.read(buf)
+    }
+}
+
+impl Write for Connection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.set_write_timeout(Some(self.time_left()?))?;
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PanicSwitch, ThreadPool, ThreadPoolBuilder};
+
+    use std::io::Read;
+
+    #[test]
+    fn test_render() {
+        let pool = ThreadPoolBuilder::new(2)
+            .panic_switch(PanicSwitch::Respawn)
+            .latency_metrics(true)
+            .build()
+            .unwrap();
+        pool.execute(|| ());
+        pool.execute(|| panic!("Oh no!"));
+        pool.execute(|| ());
+        pool.join();
+
+        let text = render("a\"b", &pool.stats(), pool.latencies().as_ref());
+        assert!(text.contains("# TYPE poolio_jobs_completed_total counter\n"));
+        assert!(text.contains("poolio_workers_configured{pool=\"a\\\"b\"} 2\n"));
+        assert!(text.contains("poolio_jobs_completed_total{pool=\"a\\\"b\"} 2\n"));
+        assert!(text.contains("poolio_jobs_panicked_total{pool=\"a\\\"b\"} 1\n"));
+        assert!(text.contains("poolio_workers_respawned_total{pool=\"a\\\"b\"} 1\n"));
+        assert!(
+            text.contains("poolio_job_run_time_seconds_bucket{pool=\"a\\\"b\",le=\"0.000001\"} ")
+        );
+        assert!(
+            text.contains("poolio_job_run_time_seconds_bucket{pool=\"a\\\"b\",le=\"+Inf\"} 3\n")
+        );
+        assert!(text.contains("poolio_job_run_time_seconds_count{pool=\"a\\\"b\"} 3\n"));
+    }
+
+    #[test]
+    fn test_exporter() {
+        const CLIENT_TIMEOUT: Duration = Duration::from_millis(200);
+
+        let timed = ThreadPoolBuilder::new(1)
+            .latency_metrics(true)
+            .build()
+            .unwrap();
+        let untimed = ThreadPool::new(1, PanicSwitch::Kill).unwrap();
+        let exporter = Exporter::new()
+            .register("timed", timed.handle())
+            .register("untimed", untimed.handle())
+            .timeout(CLIENT_TIMEOUT);
+
+        let text = exporter.render();
+        // each family is described once
+        assert_eq!(1, text.matches("# TYPE poolio_workers_live gauge").count());
+        assert!(text.contains("poolio_workers_live{pool=\"timed\"} 1\n"));
+        assert!(text.contains("poolio_workers_live{pool=\"untimed\"} 1\n"));
+        assert!(text.contains("poolio_job_queue_wait_seconds_count{pool=\"timed\"} 0\n"));
+        assert!(!text.contains("poolio_job_queue_wait_seconds_count{pool=\"untimed\"}"));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || exporter.serve(listener));
+
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let response = get("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("poolio_workers_configured{pool=\"untimed\"} 1\n"));
+        assert!(get("/").starts_with("HTTP/1.1 404 NOT FOUND\r\n"));
+
+        // a request beyond the limit is not read to its end
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let head = "GET /metrics HTTP/1.1\r\nX-Padding: ";
+        let padding = "a".repeat(MAX_REQUEST as usize - head.len());
+        write!(stream, "{}{}", head, padding).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 431 REQUEST HEADER FIELDS TOO LARGE\r\n"));
+
+        // a client which never sends its request stalls the endpoint only until its time is up
+        let _silent = TcpStream::connect(addr).unwrap();
+        let response = get("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        // so does a client which sends its request too slowly
+        let mut slow = TcpStream::connect(addr).unwrap();
+        std::thread::spawn(move || {
+            write!(slow, "GET /metrics HTTP/1.1\r\n").unwrap();
+            while slow.write_all(b"X").is_ok() {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+        });
+        let start = Instant::now();
+        let response = get("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(start.elapsed() < TIMEOUT);
+    }
+}
@@ -0,0 +1,352 @@
+//! This module records a timeline of which worker of a [`ThreadPool`](crate::ThreadPool) ran which job and when.
+//!
+//! A [`Recorder`] is a [`PoolObserver`] which is registered with a pool by [`ThreadPoolBuilder::observer`](crate::ThreadPoolBuilder::observer).
+//! It keeps the most recent events in a bounded ring buffer, so it can stay enabled in production, and writes them in the [Chrome Trace Event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU) which can be loaded in `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+//! Each worker is shown as a thread of its own.
+//!
+//! # Examples
+//!
+//! Setting up a pool and writing the timeline of its jobs to a file:
+//!
+//! ```no_run
+//! use poolio::timeline::Recorder;
+//!
+//! let recorder = Recorder::new(10_000);
+//! let pool = poolio::ThreadPoolBuilder::new(4)
+//!     .observer(recorder.clone())
+//!     .build()
+//!     .unwrap();
+//!
+//! for _ in 0..100 {
+//!     pool.execute(|| std::thread::sleep(std::time::Duration::from_millis(1)));
+//! }
+//! pool.join();
+//!
+//! let file = std::fs::File::create("trace.json").unwrap();
+//! recorder.write_json(std::io::BufWriter::new(file)).unwrap();
+//! ```
+
+use crate::{PoolObserver, PoolStats, StaffNumber};
+
+use std::any::Any;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// [`Kind`] is what an [`Event`] is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    /// A job ran for the given duration (and possibly panicked).
+    Job(Duration, bool),
+    /// The worker has been respawned.
+    Respawn,
+    /// The pool has terminated with the given numbers of completed and panicked jobs.
+    Terminate(usize, usize),
+}
+
+/// [`Event`] is an entry of the timeline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Event {
+    /// what happened
+    kind: Kind,
+    /// which worker it happened to (meaningless for [`Kind::Terminate`] which is about the whole pool)
+    worker: StaffNumber,
+    /// when it happened (relative to when the recorder has been set up)
+    at: Duration,
+}
+
+/// [`Timeline`] is the state shared by the clones of a [`Recorder`].
+#[derive(Debug)]
+struct Timeline {
+    /// when the recorder has been set up
+    epoch: Instant,
+    /// maximal number of events kept
+    capacity: usize,
+    /// the most recent events
+    events: Mutex<VecDeque<Event>>,
+    /// when the jobs currently running have been started
+    running: Mutex<HashMap<StaffNumber, Duration>>,
+    /// number of events which have been pushed out of the ring buffer
+    dropped: AtomicU64,
+}
+
+impl Timeline {
+    /// Records `kind` for `worker` having happened at `at`.
+    fn push(&self, kind: Kind, worker: StaffNumber, at: Duration) {
+        let mut events = self.events.lock().unwrap();
+        if events.len() == self.capacity {
+            events.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        events.push_back(Event { kind, worker, at });
+    }
+
+    /// Records that the job run by `worker` is over.
+    fn finish(&self, worker: StaffNumber, panicked: bool) {
+        let now = self.epoch.elapsed();
+        // a job started before the recorder has been registered is ignored
+        if let Some(started) = self.running.lock().unwrap().remove(&worker) {
+            self.push(Kind::Job(now - started, panicked), worker, started);
+        }
+    }
+}
+
+/// [`Recorder`]s record a timeline of the jobs run by a [`ThreadPool`](crate::ThreadPool).
+///
+/// Clones share the same timeline, so one clone can be registered with the pool while another one writes the timeline.
+/// A recorder is supposed to be registered with a single pool since the workers are told apart by their [`StaffNumber`] only.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    /// the shared timeline
+    timeline: Arc<Timeline>,
+}
+
+impl Recorder {
+    /// Sets up a recorder.
+    /// - `capacity` is the maximal number of events kept (older ones are dropped).
+    ///
+    /// # Panics
+    ///
+    /// A panic is caused if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity != 0, "A timeline has to be able to keep events.");
+
+        Self {
+            timeline: Arc::new(Timeline {
+                epoch: Instant::now(),
+                capacity,
+                events: Mutex::new(VecDeque::with_capacity(capacity)),
+                running: Mutex::new(HashMap::new()),
+                dropped: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Tells the number of events currently kept.
+    pub fn len(&self) -> usize {
+        self.timeline.events.lock().unwrap().len()
+    }
+
+    /// Tells whether no events are kept.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Tells how many events have been dropped to make room for newer ones.
+    pub fn dropped(&self) -> u64 {
+        self.timeline.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Forgets all events kept so far.
+    pub fn clear(&self) {
+        self.timeline.events.lock().unwrap().clear();
+        self.timeline.dropped.store(0, Ordering::Relaxed);
+    }
+
+    /// Writes the events kept in the Chrome Trace Event format.
+    /// - `writer` is where the JSON document is written to.
+    ///
+    /// Jobs are written as complete events named `job` on the thread of their worker, respawns as instant events named `respawn` and the termination of the pool as a global instant event named `terminate`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if writing to `writer` failed.
+    ///
+    /// # Examples
+    ///
+    /// Recording the timeline of a single job:
+    ///
+    /// ```
+    /// use poolio::timeline::Recorder;
+    ///
+    /// let recorder = Recorder::new(100);
+    /// let pool = poolio::ThreadPoolBuilder::new(1)
+    ///     .observer(recorder.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// pool.execute(|| println!("done"));
+    /// pool.join();
+    ///
+    /// let mut json = Vec::new();
+    /// recorder.write_json(&mut json).unwrap();
+    /// assert!(String::from_utf8(json).unwrap().contains("\"name\":\"job\""));
+    /// ```
+    pub fn write_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        // the events are copied to not block the pool while writing
+        let events: Vec<Event> = self
+            .timeline
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        // the termination is about the whole pool rather than a worker
+        let workers: BTreeSet<StaffNumber> = events
+            .iter()
+            .filter(|event| !matches!(event.kind, Kind::Terminate(_, _)))
+            .map(|event| event.worker)
+            .collect();
+
+        // timestamps are in microseconds
+        let micros = |duration: Duration| duration.as_secs_f64() * 1e6;
+
+        write!(writer, "{{\"traceEvents\":[")?;
+        let mut separator = "";
+        for worker in workers {
+            write!(
+                writer,
+                "{}\n{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":1,\"tid\":{},\"args\":{{\"name\":\"worker {}\"}}}}",
+                separator, worker, worker
+            )?;
+            separator = ",";
+        }
+        for Event { kind, worker, at } in events {
+            writeln!(writer, "{}", separator)?;
+            separator = ",";
+            match kind {
+                Kind::Job(duration, panicked) => write!(
+                    writer,
+                    "{{\"name\":\"job\",\"ph\":\"X\",\"pid\":1,\"tid\":{},\"ts\":{:.3},\"dur\":{:.3},\"args\":{{\"panicked\":{}}}}}",
+                    worker,
+                    micros(at),
+                    micros(duration),
+                    panicked
+                )?,
+                Kind::Respawn => write!(
+                    writer,
+                    "{{\"name\":\"respawn\",\"ph\":\"i\",\"s\":\"t\",\"pid\":1,\"tid\":{},\"ts\":{:.3}}}",
+                    worker,
+                    micros(at)
+                )?,
+                Kind::Terminate(completed, panicked) => write!(
+                    writer,
+                    "{{\"name\":\"terminate\",\"ph\":\"i\",\"s\":\"g\",\"pid\":1,\"tid\":{},\"ts\":{:.3},\"args\":{{\"completed\":{},\"panicked\":{}}}}}",
+                    worker,
+                    micros(at),
+                    completed,
+                    panicked
+                )?,
+            }
+        }
+        writeln!(writer, "\n],\"displayTimeUnit\":\"ms\"}}")?;
+
+        writer.flush()
+    }
+}
+
+impl PoolObserver for Recorder {
+    fn job_started(&self, worker: StaffNumber) {
+        let now = self.timeline.epoch.elapsed();
+        self.timeline.running.lock().unwrap().insert(worker, now);
+    }
+
+    fn job_finished(&self, worker: StaffNumber, _duration: Duration) {
+        self.timeline.finish(worker, false);
+    }
+
+    fn job_panicked(&self, worker: StaffNumber, _payload: &(dyn Any + Send)) {
+        self.timeline.finish(worker, true);
+    }
+
+    fn worker_respawned(&self, worker: StaffNumber) {
+        let now = self.timeline.epoch.elapsed();
+        self.timeline.push(Kind::Respawn, worker, now);
+    }
+
+    fn pool_terminated(&self, stats: &PoolStats) {
+        let now = self.timeline.epoch.elapsed();
+        let kind = Kind::Terminate(stats.completed_jobs, stats.panicked_jobs);
+        self.timeline.push(kind, 0, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PanicSwitch, ShutdownMode, ThreadPoolBuilder};
+
+    #[test]
+    fn test_recorder() {
+        let recorder = Recorder::new(100);
+        let pool = ThreadPoolBuilder::new(2)
+            .panic_switch(PanicSwitch::Respawn)
+            .observer(recorder.clone())
+            .build()
+            .unwrap();
+
+        pool.execute(|| ());
+        pool.execute(|| panic!("Oh no!"));
+        pool.execute(|| ());
+        pool.shutdown(ShutdownMode::Graceful);
+
+        let events: Vec<Event> = recorder
+            .timeline
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .copied()
+            .collect();
+        let jobs = events
+            .iter()
+            .filter(|event| matches!(event.kind, Kind::Job(_, _)))
+            .count();
+        let panicked = events
+            .iter()
+            .filter(|event| matches!(event.kind, Kind::Job(_, true)))
+            .count();
+        assert_eq!(3, jobs);
+        assert_eq!(1, panicked);
+        assert!(events.iter().any(|event| event.kind == Kind::Respawn));
+        assert_eq!(
+            Some(Kind::Terminate(2, 1)),
+            events.last().map(|event| event.kind)
+        );
+
+        let mut json = Vec::new();
+        recorder.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with("{\"traceEvents\":["));
+        assert!(json.ends_with("],\"displayTimeUnit\":\"ms\"}\n"));
+        assert_eq!(3, json.matches("\"ph\":\"X\"").count());
+        assert!(json.contains("\"name\":\"terminate\""));
+    }
+
+    #[test]
+    fn test_recorder_ring_buffer() {
+        let recorder = Recorder::new(2);
+        for worker in 0..5 {
+            recorder.job_started(worker);
+            recorder.job_finished(worker, Duration::ZERO);
+        }
+
+        assert_eq!(2, recorder.len());
+        assert_eq!(3, recorder.dropped());
+        let workers: Vec<StaffNumber> = recorder
+            .timeline
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| event.worker)
+            .collect();
+        assert_eq!(vec![3, 4], workers);
+
+        recorder.clear();
+        assert!(recorder.is_empty());
+        assert_eq!(0, recorder.dropped());
+        // the termination of the pool does not show up as a worker
+        recorder.job_started(3);
+        recorder.job_finished(3, Duration::ZERO);
+        recorder.pool_terminated(&PoolStats::default());
+        let mut json = Vec::new();
+        recorder.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.contains("\"name\":\"worker 3\""));
+        assert!(!json.contains("\"name\":\"worker 0\""));
+        assert!(json.contains("\"name\":\"terminate\""));
+    }
+}